@@ -65,10 +65,20 @@
 ///     `<Type as Trait>::Assoc<…>` paths, as well as the `Self::Assoc<…>`
 ///     ones), **except when inside a `macro! { … }` invocation**.
 ///
-///   - Only lifetime GATs are supported, so no type-GATs:
+///   - Type-generic (and `const`-generic) GATs are supported as well, but
+///     since there is no `for<T>` quantification in Rust, the `Trait` no
+///     longer implies them for every `T`:
 ///
-/**     ```rust, compile_fail
-     //! No HKTs or type families yet 😔
+///       - `Self : TraitඞAssoc<T>`-like bounds are automagically added to the
+///         `fn` signatures mentioning such GATs inside the trait definition
+///         (and inside <code>#\[[apply]\([Gat!]\)\]</code>-annotated `fn`s);
+///         the lifetimes involved in such signatures (_e.g._, for a
+///         `type Window<'a, const N: usize>`) thus have to be named;
+///
+///       - implementors have to keep using `Self::Assoc<T>` (rather than the
+///         actual type) in their method signatures.
+///
+/**     ```rust
      # fn main() {}
      #[macro_use]
      extern crate nougat;
@@ -76,6 +86,10 @@
      #[gat]
      trait Collection {
          type Of<T>;
+
+         fn singleton<T> (value: T)
+           -> Self::Of<T>
+         ;
      }
 
      enum Vec_ {}
@@ -83,15 +97,224 @@
      #[gat]
      impl Collection for Vec_ {
          type Of<T> = Vec<T>;
+
+         fn singleton<T> (value: T)
+           -> Self::Of<T>
+         {
+             vec![value]
+         }
+     }
+
+     #[apply(Gat!)]
+     fn singletons<C : Collection> ()
+       -> (<C as Collection>::Of<u8>, <C as Collection>::Of<bool>)
+     {
+         (C::singleton(42), C::singleton(true))
+     }
+     ``` */
+///
+///   - The bounds of the associated types (GATs or not) may themselves
+///     involve GAT bindings, and mention the other associated types of `Self`
+///     (_e.g._,
+///     `type Lend<'a> : LendingIterator<Item<'_> = &'_ Self::Elem> where Self : 'a;`).
+///
+///   - This includes the sibling GATs of the trait (_e.g._,
+///     `type Mut<'a> : Into<Self::Ref<'a>> where Self : 'a;`). Such bounds
+///     may not be cyclic, though (`Ref` mentioning `Mut` in turn).
+///
+///   - A GAT can be marked `#[gat(covariant)]`, for the trait to feature a
+///     covariance witness, such as
+///     `fn upcast_item<'short, 'long : 'short> (it: Self::Item<'long>) -> Self::Item<'short>`
+///     (for a `type Item<'_>`), which the `#[gat] impl`s provide as the mere
+///     identity (which thus fails to compile when the type they give is not
+///     actually covariant).
+///
+///   - With `#[gat(auto_outlives)]` on the trait, the outlives bounds which
+///     its methods require of its GATs get added (with a warning), as with
+///     native GATs: _e.g._, `where Self : 'next` on a `type Item<'next>`
+///     when there is a `fn next(&mut self) -> Option<Self::Item<'_>>`.
+///     Otherwise, omitting such a bound only shows up as baffling borrow
+///     errors when using the trait.
+///
+///   - As with native GATs, the `#[gat] impl`s may omit (or weaken) the
+///     `where` clauses of a GAT, but not make them stricter than those of
+///     the trait. These are compared structurally, bound by bound (the traits
+///     by their last path segment, so that `T : ::core::cmp::Ord` matches a
+///     `T : Ord` in the trait, and along with the bounds of the `impl`
+///     itself), so a bound which the trait lacks, but which may be implied by
+///     its other bounds on the same type (_e.g._, `T : Clone` with
+///     `T : Copy`), is only warned about.
+///
+///     Unlike with native GATs, though, the trait bounds in the `where`
+///     clauses of a lifetime GAT have to hold for the whole `impl` (since it
+///     gets required for every lifetime): _e.g._, a
+///     `type Copied<'a> where T : 'a + Copy` in an `impl<T> Views<T> for …`
+///     is rejected, and requires an `impl<T : Copy>` instead.
+///
+///   - A `#[gat] impl` missing a GAT of the trait, or giving it the wrong
+///     number of generic parameters, is reported as such (_e.g._,
+///     ``missing GAT `Item<'next>` required by `LendingIterator` ``), rather
+///     than through errors about the (hidden) helper traits.
+///
+///   - GATs may have a default value, which the `impl`s omitting that GAT
+///     shall then use. Such a default may only mention `Self` and the generic
+///     parameters of the trait (and of the GAT itself):
+///
+/**     ```rust
+     # fn main() {}
+     #[macro_use]
+     extern crate nougat;
+
+     #[gat]
+     trait Borrowed {
+         type Target : ?Sized;
+
+         type Ref<'r>
+         where
+             Self : 'r,
+         =
+             &'r Self::Target
+         ;
+
+         fn borrowed(&self) -> Self::Ref<'_>;
+     }
+
+     #[gat]
+     impl Borrowed for String {
+         type Target = str;
+
+         fn borrowed(&self) -> &str {
+             self
+         }
+     }
+     ``` */
+///
+///     This is achieved by having `#[gat] impl`s query the `#[gat] trait`
+///     definition (through a hidden macro), which requires the `nougat` crate
+///     to be reachable as `::nougat`, or as per `#[gat(crate = …)]` (see
+///     below).
+///
+///   - The code generated by `#[gat]` is not `dyn`-friendly. When trait
+///     objects are needed, use `#[gat(dyn)]` on the trait definition (its
+///     `#[gat] impl`s pick it up from there, so `#[gat(dyn)]` is optional on
+///     them), to opt into an alternative (HKT-based) desugaring, where
+///     `dyn for<'n> Trait<Assoc<'n> = …>` types can be named (inside
+///     <code>#\[[apply]\([Gat!]\)\]</code>-annotated items, or through
+///     `Gat!(dyn for<'n> Trait<Assoc<'n> = …>)`):
+///
+/**     ```rust
+     # use ::core::convert::TryInto;
+     # fn main() {}
+     #[macro_use]
+     extern crate nougat;
+
+     #[gat(dyn)]
+     trait LendingIterator {
+         type Item<'next>
+         where
+             Self : 'next,
+         ;
+
+         fn next(&mut self) -> Option<Self::Item<'_>>;
+     }
+
+     struct WindowsMut<'slice, Item, const SIZE: usize> {
+         slice: &'slice mut [Item],
+         start: usize,
+     }
+
+     #[gat]
+     impl<Item, const SIZE: usize> LendingIterator for WindowsMut<'_, Item, SIZE> {
+         type Item<'next>
+         where
+             Self : 'next,
+         =
+             &'next mut [Item; SIZE]
+         ;
+
+         fn next(&mut self) -> Option<&mut [Item; SIZE]> {
+             let to_yield =
+                 self.slice
+                     .get_mut(self.start ..)?
+                     .get_mut(.. SIZE)?
+                     .try_into()
+                     .expect("slice has the right SIZE")
+             ;
+             self.start += 1;
+             Some(to_yield)
+         }
+     }
+
+     #[apply(Gat!)]
+     fn pairs<'slice> (slice: &'slice mut [u8])
+       -> Box<dyn 'slice + for<'n> LendingIterator<Item<'n> = &'n mut [u8; 2]>>
+     {
+         Box::new(WindowsMut::<'_, _, 2> { slice, start: 0 })
+     }
+     ``` */
+///
+///     Type-generic GATs are not supported by this mode, and naming a
+///     `dyn Trait<Assoc<'n> = …>` type for a trait lacking the `dyn` flavor
+///     is rejected with a dedicated error message.
+///
+///   - A `#[gat]` trait definition may mention the GATs of its supertrait
+///     (_e.g._, `Self::Item<'_>` within a
+///     `trait DoubleEnded : LendingIterator + Send`), provided it is the only
+///     one listed in its header (besides auto traits such as `Send`).
+///     The `Self::Item<'_>` of the `#[gat] impl`s of the trait then works as
+///     well, and so does `<T as DoubleEnded>::Item<'_>`, unless the
+///     supertrait is given generic args other than those of the trait (_e.g._,
+///     `trait Peek<T> : LendingIterator`), in which case the GAT has to be
+///     named through the supertrait: `<T as LendingIterator>::Item<'_>`.
+///
+///   - Other items mentioning the GATs of `Self` (_e.g._, inherent `impl`s,
+///     or the definition or `impl`s of an extension trait) can be annotated
+///     with `#[gat(Self : Trait + OtherTrait…)]`, for their `Self::Assoc<…>`
+///     paths to resolve to the one listed `#[gat]` trait having such a GAT
+///     (when several of them do, the path has to be qualified):
+///
+/**     ```rust
+     # fn main() {}
+     #[macro_use]
+     extern crate nougat;
+
+     #[gat]
+     trait LendingIterator {
+         type Item<'next>
+         where
+             Self : 'next,
+         ;
+
+         fn next(&mut self) -> Option<Self::Item<'_>>;
+     }
+
+     #[gat(Self : LendingIterator)]
+     trait LendingIteratorExt : LendingIterator {
+         fn second(&mut self) -> Option<Self::Item<'_>> {
+             let _ = self.next()?;
+             self.next()
+         }
      }
      ``` */
 ///
 ///  - ⚠️ When `use` or `pub use`ing a `#[gat]`-annotated trait, make sure
-///    to annotate such `use` "statement" with `#[gat(AssocItem)]`.
+///    to annotate such `use` "statement" with `#[gat]`.
 ///
 ///    ⚠️ **Otherwise it won't be possible to implement that trait through the
 ///    new path** ⚠️
 ///
+///    The GATs of the trait are then all re-exported along with it (querying
+///    the trait definition, like `#[gat] impl`s do). They can also be listed
+///    explicitly, as in `#[gat(AssocItem)]`, to only re-export those (in
+///    which case the import has to be of a single trait).
+///
+///    Groups (_e.g._, `use path::{Trait, to::Other as Renamed};`) and globs
+///    are supported as well: a glob import already brings the hidden helpers
+///    along, provided they are as visible as the trait. Every item which is
+///    explicitly named in such a group has to be a `#[gat]` trait, though
+///    (otherwise that item is reported, with a ``cannot find `ItemඞGATs` ``
+///    error): import the other items through a separate, plain `use`.
+///
 ///    For instance:
 /**    ```rust
     extern crate nougat as nou;
@@ -105,9 +328,16 @@
     }
 
     // ⚠️ DO NOT FORGET TO ADD THIS ⚠️
-    #[nou::gat(Item)] // 👈
+    #[nou::gat] // 👈
     pub use example::LendingIterator;
     ``` */
+///
+///  - The `#[gat] impl`s (and `use`s, and some `Gat!` types) query the trait
+///    definition through `::nougat`. When `nougat` is only reachable through
+///    a re-export (or a renamed dependency), the `#[gat] trait` definition
+///    has to say where to find it, as in
+///    `#[gat(crate = crate::path::to::nougat)]` (a `crate::`-relative path
+///    keeps working for the downstream crates implementing the trait).
 pub use ::nougat_proc_macros::gat;
 
 /// Refer to a `<Type as Trait>::Assoc<…>` type.
@@ -229,10 +459,46 @@ pub use ::nougat_proc_macros::gat;
 /// of `<Type as Trait>`, since macros, such as this one, don't have access to
 /// that compiler resolution information, only to syntactical paths.
 ///
-/// The only hard-coded exception to this rule is when inside a
-/// <code>[#\[gat\]]</code> trait definition or implementation: there, not only
-/// is `Gat!` automagically applied where applicable, the `Self::Assoc<…>` types
-/// also become `<Self as Trait>::Assoc<…>`.
+/// The only hard-coded exceptions to this rule are:
+///
+///   - when inside a <code>[#\[gat\]]</code> trait definition or
+///     implementation: there, not only is `Gat!` automagically applied where
+///     applicable, the `Self::Assoc<…>` types also become
+///     `<Self as Trait>::Assoc<…>`;
+///
+///   - when inside a <code>[#\[gat\]]</code> or
+///     <code>#\[[apply]\([Gat!]\)\]</code>-annotated item, the `I::Assoc<…>`
+///     shorthand, for a generic type parameter `I` with exactly one trait bound
+///     (_e.g._, `I : LendingIterator`, or `where I : LendingIterator`), which
+///     becomes `<I as LendingIterator>::Assoc<…>`. Auto traits, such as `Send`
+///     or `Sync`, are not taken into account, since they cannot have
+///     associated types; should `I` have several other bounds (_e.g._,
+///     `I : LendingIterator + Clone`), the path has to be qualified.
+///
+/// Nested associated paths, such as
+/// `<I as LendingIterator>::Item<'lt>::Output`, are supported when the GAT has
+/// exactly one trait bound (_e.g._, `type Item<'next> : Deref`), through which
+/// the nested associated item is then projected (and so on, should it be a GAT
+/// itself). Otherwise, the path has to be qualified:
+/// `<<I as LendingIterator>::Item<'lt> as Deref>::Target`.
+///
+/// GAT equality bindings may elide the GAT lifetime, as in
+/// `I : LendingIterator<Item<'_> = &'_ str>`, which is sugar for
+/// `I : for<'n> LendingIterator<Item<'n> = &'n str>` (the `'_` of the bound
+/// type thus referring to the GAT lifetime).
+///
+/// Bounds on a GAT can be written with the associated-type-bound syntax
+/// (_e.g._, `I : for<'n> LendingIterator<Item<'n> : Debug + Send>`), which
+/// becomes an extra `where` clause:
+/// `for<'n> <I as LendingIterator>::Item<'n> : Debug + Send`.
+///
+/// ⚠️ On the MSRV (Rust 1.53.0), such a higher-ranked `where` clause can only
+/// be satisfied by a generic caller featuring it as well: the compiler fails
+/// to check it against a concrete type (_e.g._, calling such a function with
+/// a concrete iterator, or bounding a concrete type that way), which requires
+/// a more recent toolchain. Bounds for a given lifetime, as in
+/// `I : LendingIterator<Item<'a> : Debug>`, are not higher-ranked, and are
+/// thus not affected.
 ///
 /// [#\[gat\]]: gat
 pub use ::nougat_proc_macros::Gat;
@@ -244,6 +510,17 @@ pub use ::nougat_proc_macros::Gat;
 /// `Gat!` to each `<Type as Trait>::Assoc<…>` occurrence.
 pub use ::macro_rules_attribute::apply;
 
+#[doc(hidden)] /** Not part of the public API */ pub
+mod ඞ {
+    pub use ::nougat_proc_macros::{
+        __gat_dyn,
+        __gat_impl,
+        __gat_nested,
+        __gat_self,
+        __gat_use,
+    };
+}
+
 #[cfg_attr(feature = "ui-tests",
     cfg_attr(all(), doc = include_str!("compile_fail_tests.md")),
 )]
@@ -4,6 +4,7 @@ pub(in super)
 enum Input {
     TypePath(TypePath),
     TypeImpl(TypeImplTrait),
+    TypeTraitObject(TypeTraitObject),
     Item(Item),
 }
 
@@ -23,6 +24,9 @@ fn Gat<Error : SynError> (
             fn parse (input: ParseStream<'_>)
               -> Result<Input>
             {
+                if input.peek(Token![dyn]) {
+                    return input.parse().map(Input::TypeTraitObject);
+                }
                 if input.peek(Token![<]).not() {
                     let ref fork = input.fork();
                     if let ty_impl @ Ok(_) = fork.parse().map(Input::TypeImpl) {
@@ -42,12 +46,22 @@ fn Gat<Error : SynError> (
         match input {
             | Input::TypePath(it) => it,
             | Input::TypeImpl(mut it) => {
-                handle_trait_bounds(&mut it.bounds);
+                // No bounded type to express GAT constraints with.
+                if let Err(err) = handle_trait_bounds(&mut it.bounds, None) {
+                    utils::recover(err);
+                }
                 return Ok(utils::mb_file_expanded(it.into_token_stream()));
             },
+            | Input::TypeTraitObject(it) => {
+                // The trait object is expected to involve a `#[gat]` trait:
+                // check it is a `#[gat(dyn)]` one, even without GAT bindings.
+                return Ok(utils::mb_file_expanded(match main_trait_of(&it) {
+                    | Some(idx) => defer_trait_object(&it, idx),
+                    | None => it.into_token_stream(),
+                }));
+            },
             | Input::Item(item) => return Ok(utils::mb_file_expanded(
-                adjugate::adjugate(parse::Nothing, item)
-                    .into_token_stream()
+                adjugate::adjugate(parse::Nothing, item).into_token_stream()
             )),
         }
     };
@@ -63,14 +77,39 @@ fn Gat<Error : SynError> (
 
     let pos = qself.position;
     assert!(path.segments.len() > pos);
+    // `<<X as A>::Gat1<'a> as B>::Gat2<'b>`
+    //   ^^^^^^^^^^^^^^^^^^
+    let mut qself = qself;
+    adjugate::adjugate_type(&mut qself.ty);
     if let Some(Pair::Punctuated(_, p)) = path.segments.pairs().nth(pos) {
         //                                            p
-        // <Ty as path::to::LendingIterator<…>>:: Item::AndYetAnotherAssoc…
-        bail! {
-            "nested associated paths are not supported" => p,
+        // <Ty as path::to::LendingIterator<…>>:: Item<…>::AndYetAnotherAssoc…
+        if matches!(
+            path.segments[pos].arguments,
+            PathArguments::AngleBracketed { .. },
+        ).not()
+        {
+            bail! {
+                "nested associated paths are only supported through GATs" => p,
+            }
         }
+        // The trait through which to project the GAT is not known from here:
+        // ask the `#[gat] trait` for the bounds of its GAT (see `nested()`).
+        let mut PathToTraitGATs @ _ = Path {
+            leading_colon: path.leading_colon,
+            segments: path.segments.iter().take(pos).cloned().collect(),
+        };
+        let trait_ = PathToTraitGATs.segments.last_mut().unwrap();
+        trait_.ident = combine_trait_name_into_gats_macro(&trait_.ident);
+        trait_.arguments = PathArguments::None;
+        let type_path = TypePath { qself: Some(qself), path };
+        return Ok(quote!(
+            #PathToTraitGATs! {
+                [__gat_nested]
+                #type_path
+            }
+        ));
     }
-    debug_assert!(path.segments.len() == pos + 1);
 
     // `Item<…>`
     let mut last_segment = mem::replace(
@@ -86,7 +125,7 @@ fn Gat<Error : SynError> (
     ;
     let generics = match last_segment.arguments {
         | PathArguments::None { .. } => bail! {
-            "missing lifetime generics" => last_segment.ident,
+            "missing GAT generics" => last_segment.ident,
         },
         | PathArguments::Parenthesized { .. } => bail! {
             "expected `<`" => last_segment.arguments,
@@ -114,7 +153,7 @@ fn Gat<Error : SynError> (
             ref mut args,
             ..
         }) => {
-            generics.extend(mem::take(args));
+            merge_generic_args(generics, mem::take(args));
         },
     }
     // `<… as path::to::LendingIteratorItem<…>>::…`.
@@ -123,135 +162,712 @@ fn Gat<Error : SynError> (
     Ok(TypePath { qself: Some(qself), path }.into_token_stream())
 }
 
-type TraitBounds = Punctuated<TypeParamBound, Token![+]>;
+/// The second half of a nested `Gat!` path, once the metadata of the `trait`
+/// has been fetched:
+///
+/// `<X as Trait<…>>::Assoc<…>::Nested…`, with `type Assoc<…> : Bound<…>`,
+/// becomes `<<X as TraitඞAssoc<…>>::T as Bound<…>>::Nested…`.
+pub(in super)
+fn nested (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let (metadata, TypePath { qself, mut path }) = Parser::parse2(
+        |input: ParseStream<'_>| Ok((
+            {
+                let metadata;
+                braced!(metadata in input);
+                metadata.parse::<gat::TraitMetadata>()?
+            },
+            input.parse::<TypePath>()?,
+        )),
+        input,
+    )?;
+    let qself = qself.unwrap();
+    let pos = qself.position;
+    // `::Nested…`
+    let nested: Vec<PathSegment> =
+        path.segments
+            .iter()
+            .skip(pos + 1)
+            .cloned()
+            .collect()
+    ;
+    path.segments = path.segments.into_iter().take(pos + 1).collect();
+    let Assoc @ _ = &path.segments[pos];
+    let gat =
+        match metadata.gats().find(|gat| gat.ident == Assoc.ident) {
+            | Some(it) => it,
+            | None => bail! {
+                "not a GAT of this `#[gat] trait`" => Assoc.ident,
+            },
+        }
+    ;
+    let mut bounds =
+        gat .bounds
+            .iter()
+            .filter_map(|bound| match *bound {
+                | TypeParamBound::Trait(ref it)
+                    if matches!(it.modifier, TraitBoundModifier::None)
+                => {
+                    Some(it)
+                },
+                | _ => None,
+            })
+    ;
+    let mut Bound @ _ = match (bounds.next(), bounds.next()) {
+        | (Some(it), None) => it.clone(),
+        | _ => bail! {
+            "the trait of a nested GAT can only be inferred when the GAT has \
+            exactly one trait bound: qualify it, as in \
+            `<<Ty as Trait>::Assoc<…> as OtherTrait>::Nested`"
+            => Assoc.ident,
+        },
+    };
+    // `Self` becomes `X`, and the generics become the given args.
+    let no_args = Punctuated::new();
+    let gat_args = match Assoc.arguments {
+        | PathArguments::AngleBracketed(ref it) => &it.args,
+        | _ => &no_args,
+    };
+    let PathToTrait @ _ = Path {
+        leading_colon: path.leading_colon,
+        segments: path.segments.iter().take(pos).cloned().collect(),
+    };
+    let ref mut substitute = gat::SubstituteTraitGenerics::new(
+        &PathToTrait,
+        &metadata.trait_.generics,
+        (*qself.ty).clone(),
+    )?;
+    substitute.add_generics(&gat.generics, gat_args);
+    visit_mut::VisitMut::visit_trait_bound_mut(substitute, &mut Bound);
+    // `Bound<…, Assoc = …>` becomes `Bound<…>`
+    if let Some(PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+        ref mut args,
+        ..
+    })) = Bound.path.segments.last_mut().map(|it| &mut it.arguments)
+    {
+        *args = mem::take(args).into_iter().filter(|arg| matches!(
+            *arg,
+            GenericArgument::Binding { .. } | GenericArgument::Constraint { .. },
+        ).not()).collect();
+    }
+    let Bound = Bound.path;
+    // `<X as TraitඞAssoc<…>>::T`
+    let Ty @ _ =
+        Gat::<Error>(Input::TypePath(TypePath { qself: Some(qself), path }))?
+    ;
+    let type_path: TypePath = parse_quote!(
+        <#Ty as #Bound> #(:: #nested)*
+    );
+    // Another GAT hop?
+    if matches!(nested[0].arguments, PathArguments::AngleBracketed { .. }) {
+        Gat::<Error>(Input::TypePath(type_path))
+    } else {
+        Ok(type_path.into_token_stream())
+    }
+}
 
+/// Merges `Trait<…>`'s generic args into `Assoc<…>`'s, following the order
+/// of the generic params of the helper `TraitඞAssoc` trait:
+/// `<'gat…, 'trait…, GatTyOrConst…, TraitTyOrConst…>`.
+///
+/// Type and `const` args are kept in their (positional) order, so that the
+/// trailing defaulted params of `Trait` can still be omitted. The bindings and
+/// constraints of `Trait<…>`'s other associated types, if any, are skipped.
 pub(in crate)
-fn handle_trait_bounds (
-    trait_bounds: &mut TraitBounds,
+fn merge_generic_args (
+    gat_args: &'_ mut Punctuated<GenericArgument, Token![,]>,
+    trait_args: Punctuated<GenericArgument, Token![,]>,
 )
 {
-    let mut extra_bounds = vec![];
-    trait_bounds
-        .iter_mut()
-        .filter_map(|it| match it {
-            | TypeParamBound::Trait(trait_bound) => Some(trait_bound),
-            | _ => None,
+    let (trait_lifetimes, trait_tys_and_consts): (Vec<_>, Vec<_>) =
+        trait_args
+            .into_iter()
+            .filter(|it| matches!(it,
+                GenericArgument::Lifetime { .. } |
+                GenericArgument::Type { .. } |
+                GenericArgument::Const { .. }
+            ))
+            .partition(|it| matches!(it, GenericArgument::Lifetime { .. }))
+    ;
+    let pos = count_lifetime_args(gat_args);
+    for (i, lifetime) in trait_lifetimes.into_iter().enumerate() {
+        gat_args.insert(pos + i, lifetime);
+    }
+    gat_args.extend(trait_tys_and_consts);
+}
+
+type TraitBounds = Punctuated<TypeParamBound, Token![+]>;
+
+/// `Assoc<'a, …> = Ty`
+struct GatBinding {
+    ident: Ident,
+    lifetimes: Punctuated<Lifetime, Token![,]>,
+    eq_token: Token![=],
+    ty: Type,
+}
+
+impl Parse for GatBinding {
+    fn parse (input: ParseStream<'_>)
+      -> Result<GatBinding>
+    {
+        Ok(GatBinding {
+            ident: input.parse()?,
+            lifetimes: {
+                let _: Token![<] = input.parse()?;
+                let mut it = Punctuated::new();
+                while let Some(lt) = input.parse()? {
+                    it.push_value(lt);
+                    if let Some(p) = input.parse()? {
+                        it.push_punct(p);
+                    } else {
+                        break;
+                    }
+                }
+                let _: Token![>] = input.parse()?;
+                it
+            },
+            eq_token: input.parse()?,
+            ty: input.parse()?,
         })
-        .for_each(|trait_bound| {
-            let Trait @ _ = trait_bound.path.segments.last_mut().unwrap();
-            let generics = match Trait.arguments {
-                | PathArguments::AngleBracketed(ref mut it) => it,
-                | _ => return,
+    }
+}
+
+impl GatBinding {
+    /// `Assoc<'_> = &'_ Ty` sugar for `for<'ඞ0> … Assoc<'ඞ0> = &'ඞ0 Ty`:
+    /// names the elided GAT lifetimes (as well as the `'_` in the bound type,
+    /// when there is exactly one of them), and returns these names, to be
+    /// `for<…>`-quantified.
+    fn name_elided_lifetimes (
+        self: &'_ mut GatBinding,
+    ) -> Result<Vec<Lifetime>>
+    {
+        let mut named = vec![];
+        for lifetime in self.lifetimes.iter_mut() {
+            if lifetime.ident == "_" {
+                *lifetime = Lifetime::new(
+                    &format!("'ඞ{}", named.len()),
+                    lifetime.span(),
+                );
+                named.push(lifetime.clone());
+            }
+        }
+        if let Some(first) = named.first() {
+            let mut visitor = ReplaceElidedLifetimes {
+                by: first.clone(),
+                first_replaced: None,
             };
-            let mut to_drain = vec![];
-            let mut bindings = vec![];
-            struct GatBinding {
-                ident: Ident,
-                lifetimes: Punctuated<Lifetime, Token![,]>,
-                eq_token: Token![=],
-                ty: Type,
+            visit_mut::VisitMut::visit_type_mut(&mut visitor, &mut self.ty);
+            if let (true, Some(elided)) =
+                (named.len() > 1, visitor.first_replaced)
+            {
+                bail! {
+                    "ambiguous `'_`: several GAT lifetimes have been elided, \
+                    so name them, as in `for<'a, 'b> …<Assoc<'a, 'b> = …>`"
+                    => elided,
+                }
             }
-            generics.args.iter().enumerate().for_each(|(i, arg)| match arg {
+        }
+        Ok(named)
+    }
+}
+
+/// Replaces the `'_` lifetimes with the given one, except within `fn(…)`
+/// pointers or `Fn…(…)` bounds, which have elision rules of their own.
+struct ReplaceElidedLifetimes {
+    by: Lifetime,
+    first_replaced: Option<Lifetime>,
+}
+
+impl visit_mut::VisitMut for ReplaceElidedLifetimes {
+    fn visit_lifetime_mut (
+        self: &'_ mut ReplaceElidedLifetimes,
+        lifetime: &'_ mut Lifetime,
+    )
+    {
+        if lifetime.ident == "_" {
+            let elided = mem::replace(lifetime, self.by.clone());
+            self.first_replaced.get_or_insert(elided);
+        }
+    }
+
+    fn visit_type_bare_fn_mut (
+        self: &'_ mut ReplaceElidedLifetimes,
+        _: &'_ mut TypeBareFn,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_parenthesized_generic_arguments_mut (
+        self: &'_ mut ReplaceElidedLifetimes,
+        _: &'_ mut ParenthesizedGenericArguments,
+    )
+    {
+        /* do not subrecurse */
+    }
+}
+
+/// Removes the GAT bindings from the given generic args, and returns them.
+fn drain_gat_bindings (
+    args: &'_ mut Punctuated<GenericArgument, Token![,]>,
+) -> Vec<GatBinding>
+{
+    let mut bindings = vec![];
+    *args =
+        mem::take(args)
+            .into_iter()
+            .filter_map(|arg| match arg {
                 // syn 1.* cannot handle GAT `Binding`s
                 // so it currently falls back to a verbatim.
-                | GenericArgument::Type(Type::Verbatim(tokens)) => {
-                    impl Parse for GatBinding {
-                        fn parse (input: ParseStream<'_>)
-                          -> Result<GatBinding>
-                        {
-                            Ok(GatBinding {
-                                ident: input.parse()?,
-                                lifetimes: {
-                                    let _: Token![<] = input.parse()?;
-                                    let mut it = Punctuated::new();
-                                    while let Some(lt) = input.parse()? {
-                                        it.push_value(lt);
-                                        if let Some(p) = input.parse()? {
-                                            it.push_punct(p);
-                                        } else {
-                                            break;
-                                        }
-                                    }
-                                    let _: Token![>] = input.parse()?;
-                                    it
-                                },
-                                eq_token: input.parse()?,
-                                ty: input.parse()?,
-                            })
-                        }
-                    }
-                    if let Ok(binding) = parse2::<GatBinding>(tokens.clone()) {
-                        to_drain.push(i);
-                        bindings.push(binding);
+                | GenericArgument::Type(Type::Verbatim(ref tokens)) => {
+                    match parse2::<GatBinding>(tokens.clone()) {
+                        | Ok(binding) => {
+                            bindings.push(binding);
+                            None
+                        },
+                        | Err(_) => Some(arg),
                     }
                 },
-                | _ => {},
-            });
-            if to_drain.is_empty().not() {
-                // Remove the GAT bindings.
-                generics.args =
-                    mem::take(&mut generics.args)
-                        .into_iter()
-                        .enumerate()
-                        .filter_map(|(i, arg)| {
-                            to_drain.contains(&i).not().then(|| arg)
-                        })
-                        .collect()
-                ;
-                // generate the extra super traits
-                for GatBinding {
-                        ident: Assoc @ _,
-                        lifetimes: gat_lifetimes,
-                        eq_token: eq_,
-                        ty,
-                    }
-                        in bindings
-                {
-                    let generics =
-                        match trait_bound
-                                .path
-                                .segments
-                                .last()
-                                .unwrap()
-                                .arguments
-                        {
-                            | PathArguments::AngleBracketed(ref it) => it,
-                            | _ => return,
-                        }
-                    ;
-                    let each_generic_lt =
-                        generics.args.iter().filter(|it| matches!(it,
-                            GenericArgument::Lifetime { .. }
-                        ))
-                    ;
-                    let each_generic_ty_or_const =
-                        generics.args.iter().filter(|it| matches!(it,
-                            GenericArgument::Type { .. } |
-                            GenericArgument::Const { .. }
-                        ))
-                    ;
-                    let mut trait_bound = trait_bound.clone();
-                    let last_segment = trait_bound.path.segments.last_mut().unwrap();
-                    let Trait_Assoc = combine_trait_name_and_assoc_type(
-                        &last_segment.ident,
-                        &Assoc,
-                    );
-                    *last_segment = parse_quote!(
-                        #Trait_Assoc<
-                            #(#each_generic_lt ,)*
-                            #gat_lifetimes,
-                            #(#each_generic_ty_or_const ,)*
-                            T #eq_ #ty,
-                        >
-                    );
-                    extra_bounds.push(trait_bound);
+                | _ => Some(arg),
+            })
+            .collect()
+    ;
+    bindings
+}
+
+/// `Assoc<'a, …> : Bounds…`
+struct GatConstraint {
+    ident: Ident,
+    lifetimes: Punctuated<Lifetime, Token![,]>,
+    bounds: TraitBounds,
+}
+
+impl Parse for GatConstraint {
+    fn parse (input: ParseStream<'_>)
+      -> Result<GatConstraint>
+    {
+        let ident = input.parse()?;
+        let lifetimes = {
+            let _: Token![<] = input.parse()?;
+            let mut it = Punctuated::new();
+            while let Some(lt) = input.parse()? {
+                it.push_value(lt);
+                if let Some(p) = input.parse()? {
+                    it.push_punct(p);
+                } else {
+                    break;
                 }
             }
+            let _: Token![>] = input.parse()?;
+            it
+        };
+        let _: Token![:] = input.parse()?;
+        Ok(GatConstraint {
+            ident,
+            lifetimes,
+            bounds: TraitBounds::parse_separated_nonempty(input)?,
         })
+    }
+}
+
+/// Removes the GAT constraints from the given generic args, and returns them.
+fn drain_gat_constraints (
+    args: &'_ mut Punctuated<GenericArgument, Token![,]>,
+) -> Vec<GatConstraint>
+{
+    let mut constraints = vec![];
+    *args =
+        mem::take(args)
+            .into_iter()
+            .filter_map(|arg| match arg {
+                // Same as for `drain_gat_bindings()`.
+                | GenericArgument::Type(Type::Verbatim(ref tokens)) => {
+                    match parse2::<GatConstraint>(tokens.clone()) {
+                        | Ok(constraint) => {
+                            constraints.push(constraint);
+                            None
+                        },
+                        | Err(_) => Some(arg),
+                    }
+                },
+                | _ => Some(arg),
+            })
+            .collect()
     ;
+    constraints
+}
+
+/// Handles the `Trait<Assoc<'a> = Ty>` bindings, by adding the extra
+/// `TraitඞAssoc<'a, T = Ty>` bounds, and the `Trait<Assoc<'a> : Bounds>`
+/// constraints (associated type bounds not being available on stable Rust),
+/// by returning the
+/// `for<'a> <BoundedTy as TraitඞAssoc<'a>>::T : Bounds` predicates to add
+/// to the `where` clause.
+///
+/// The latter require the bounded type (which `impl Trait` types, or the
+/// bounds of associated types, lack).
+pub(in crate)
+fn handle_trait_bounds (
+    trait_bounds: &mut TraitBounds,
+    bounded: Option<(&'_ Type, Option<&'_ BoundLifetimes>)>,
+) -> Result<Vec<WherePredicate>>
+{
+    let mut extra_predicates = vec![];
+    for trait_bound in trait_bounds.iter_mut().filter_map(|it| match it {
+        | TypeParamBound::Trait(trait_bound) => Some(trait_bound),
+        | _ => None,
+    })
+    {
+        let constraints = match
+            trait_bound.path.segments.last_mut().unwrap().arguments
+        {
+            | PathArguments::AngleBracketed(ref mut it) => {
+                drain_gat_constraints(&mut it.args)
+            },
+            | _ => continue,
+        };
+        let (BoundedTy, predicate_lifetimes) =
+            match (bounded, constraints.first())
+        {
+            | (_, None) => continue,
+            | (Some(it), _) => it,
+            | (None, Some(GatConstraint { ident: Assoc @ _, .. })) => bail! {
+                "bounds on a GAT are only supported on generic type \
+                parameters (or in `where` clauses), as in \
+                `I : Trait<Assoc<…> : Bounds>`" => Assoc,
+            },
+        }
+        ;
+        let each_lifetime =
+            predicate_lifetimes
+                .into_iter()
+                .chain(&trait_bound.lifetimes)
+                .flat_map(|it| &it.lifetimes)
+                .collect::<Vec<_>>()
+        ;
+        let Trait @ _ = &trait_bound.path;
+        for GatConstraint { ident: Assoc @ _, lifetimes, bounds } in
+            constraints
+        {
+            // `<BoundedTy as TraitඞAssoc<'a, …>>::T`
+            let projection =
+                Gat::<Error>(Input::TypePath(parse_quote!(
+                    <#BoundedTy as #Trait>::#Assoc<#lifetimes>
+                )))?
+            ;
+            extra_predicates.push(if each_lifetime.is_empty() {
+                parse_quote!(
+                    #projection : #bounds
+                )
+            } else {
+                parse_quote!(
+                    for<#(#each_lifetime),*> #projection : #bounds
+                )
+            });
+        }
+    }
+    let mut extra_bounds = vec![];
+    for trait_bound in trait_bounds.iter_mut().filter_map(|it| match it {
+        | TypeParamBound::Trait(trait_bound) => Some(trait_bound),
+        | _ => None,
+    })
+    {
+        let Trait @ _ = trait_bound.path.segments.last_mut().unwrap();
+        let generics = match Trait.arguments {
+            | PathArguments::AngleBracketed(ref mut it) => it,
+            | _ => continue,
+        };
+        let bindings = drain_gat_bindings(&mut generics.args);
+        // generate the extra super traits
+        for mut binding in bindings {
+            let elided_lifetimes = binding.name_elided_lifetimes()?;
+            let GatBinding {
+                ident: Assoc @ _,
+                lifetimes: gat_lifetimes,
+                eq_token: eq_,
+                ty,
+            } = binding;
+            let mut trait_bound = trait_bound.clone();
+            if elided_lifetimes.is_empty().not() {
+                trait_bound
+                    .lifetimes
+                    .get_or_insert_with(|| parse_quote!(for<>))
+                    .lifetimes
+                    .extend(elided_lifetimes.into_iter().map(LifetimeDef::new))
+                ;
+            }
+            let last_segment = trait_bound.path.segments.last_mut().unwrap();
+            let mut args: Punctuated<_, Token![,]> =
+                gat_lifetimes
+                    .iter()
+                    .cloned()
+                    .map(GenericArgument::Lifetime)
+                    .collect()
+            ;
+            if let PathArguments::AngleBracketed(ref it) = last_segment.arguments {
+                merge_generic_args(&mut args, it.args.clone());
+            }
+            let Trait_Assoc = combine_trait_name_and_assoc_type(
+                &last_segment.ident,
+                &Assoc,
+            );
+            let each_arg = args.iter();
+            *last_segment = parse_quote!(
+                #Trait_Assoc<
+                    #(#each_arg ,)*
+                    T #eq_ #ty,
+                >
+            );
+            extra_bounds.push(trait_bound);
+        }
+    }
     trait_bounds.extend(
         extra_bounds.into_iter().map(TypeParamBound::Trait)
     );
+    Ok(extra_predicates)
+}
+
+/// Trait objects cannot feature extra (non-auto) traits, so the GAT bindings
+/// are expressed in terms of the HKT of the `#[gat(dyn)]` traits instead:
+///
+/// `dyn for<'n> Trait<Assoc<'n> = Ty>` becomes
+/// `dyn Trait<Assoc = dyn for<'n> TraitඞAssocඞHKT<'n, T = Ty>>`.
+pub(in crate)
+fn handle_dyn_trait_bounds (
+    trait_bounds: &mut TraitBounds,
+) -> Result<()>
+{
+    for trait_bound in trait_bounds.iter_mut().filter_map(|it| match it {
+        | TypeParamBound::Trait(trait_bound) => Some(trait_bound),
+        | _ => None,
+    })
+    {
+        let bindings = match
+            trait_bound.path.segments.last_mut().unwrap().arguments
+        {
+            | PathArguments::AngleBracketed(ref mut it) => {
+                drain_gat_bindings(&mut it.args)
+            },
+            | _ => continue,
+        };
+        if bindings.is_empty() {
+            continue;
+        }
+        let mut HKT @ _ = trait_bound.path.clone();
+        let trait_args = match
+            mem::replace(
+                &mut HKT.segments.last_mut().unwrap().arguments,
+                PathArguments::None,
+            )
+        {
+            | PathArguments::AngleBracketed(it) => {
+                it  .args
+                    .into_iter()
+                    .filter(|it| matches!(it,
+                        GenericArgument::Lifetime { .. } |
+                        GenericArgument::Type { .. } |
+                        GenericArgument::Const { .. }
+                    ))
+                    .collect::<Punctuated<_, Token![,]>>()
+            },
+            | _ => unreachable!(),
+        };
+        let mut dyn_bindings: Vec<GenericArgument> = vec![];
+        let mut each_gat_lifetime = vec![];
+        for mut binding in bindings {
+            // The named elided lifetimes get quantified by the inner
+            // `dyn for<…>`, as any other GAT lifetime.
+            binding.name_elided_lifetimes()?;
+            let GatBinding {
+                ident: Assoc @ _,
+                lifetimes: gat_lifetimes,
+                eq_token: eq_,
+                ty,
+            } = binding;
+            let mut args: Punctuated<_, Token![,]> =
+                gat_lifetimes
+                    .iter()
+                    .cloned()
+                    .map(GenericArgument::Lifetime)
+                    .collect()
+            ;
+            merge_generic_args(&mut args, trait_args.clone());
+            let mut HKT @ _ = HKT.clone();
+            let last_segment = HKT.segments.last_mut().unwrap();
+            let Trait_Assoc_HKT = combine_trait_name_and_assoc_type_into_hkt(
+                &last_segment.ident,
+                &Assoc,
+            );
+            let each_arg = args.iter();
+            *last_segment = parse_quote!(
+                #Trait_Assoc_HKT<
+                    #(#each_arg ,)*
+                    T #eq_ #ty,
+                >
+            );
+            dyn_bindings.push(parse_quote!(
+                #Assoc = dyn for<#gat_lifetimes> #HKT
+            ));
+            each_gat_lifetime.extend(gat_lifetimes);
+        }
+        match trait_bound.path.segments.last_mut().unwrap().arguments {
+            | PathArguments::AngleBracketed(ref mut it) => {
+                it.args.extend(dyn_bindings);
+            },
+            | _ => unreachable!(),
+        }
+        // The GAT lifetimes are now quantified by the inner `dyn for<…>`.
+        if let Some(ref mut bound_lifetimes) = trait_bound.lifetimes {
+            bound_lifetimes.lifetimes =
+                mem::take(&mut bound_lifetimes.lifetimes)
+                    .into_iter()
+                    .filter(|it| {
+                        each_gat_lifetime.contains(&it.lifetime).not()
+                    })
+                    .collect()
+            ;
+            if bound_lifetimes.lifetimes.is_empty() {
+                trait_bound.lifetimes = None;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `dyn for<'n> Trait<Assoc<'n> = Ty>`, within an `#[apply(Gat!)]`-annotated
+/// item: deferred through `Trait`'s `TraitඞGATs!` macro (see
+/// `trait_object()`), if there are GAT bindings, or left untouched.
+pub(in crate)
+fn handle_trait_object (
+    trait_object: &'_ TypeTraitObject,
+) -> Option<TokenStream2>
+{
+    gat_bound_of(trait_object).map(|idx| {
+        defer_trait_object(trait_object, idx)
+    })
+}
+
+/// The position of the trait bound with GAT bindings, if any.
+fn gat_bound_of (
+    trait_object: &'_ TypeTraitObject,
+) -> Option<usize>
+{
+    trait_object.bounds.iter().position(|bound| match *bound {
+        | TypeParamBound::Trait(TraitBound { ref path, .. }) => {
+            match path.segments.last().unwrap().arguments {
+                | PathArguments::AngleBracketed(ref it) => {
+                    drain_gat_bindings(&mut it.args.clone())
+                        .is_empty()
+                        .not()
+                },
+                | _ => false,
+            }
+        },
+        | _ => false,
+    })
+}
+
+/// The position of the trait bound with GAT bindings, or else, of the only
+/// non-auto trait bound (should there be several, `rustc` rejects the trait
+/// object on its own, so it is left untouched).
+fn main_trait_of (
+    trait_object: &'_ TypeTraitObject,
+) -> Option<usize>
+{
+    gat_bound_of(trait_object).or_else(|| {
+        let mut each_non_auto_trait =
+            trait_object
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|&(_, bound)| matches!(
+                    *bound,
+                    TypeParamBound::Trait(TraitBound { ref path, .. })
+                    if is_auto_trait(path).not()
+                ))
+                .map(|(idx, _)| idx)
+        ;
+        match (each_non_auto_trait.next(), each_non_auto_trait.next()) {
+            | (Some(idx), None) => Some(idx),
+            | _ => None,
+        }
+    })
+}
+
+/// `TraitඞGATs! { [__gat_dyn] dyn … }`
+fn defer_trait_object (
+    trait_object: &'_ TypeTraitObject,
+    idx: usize,
+) -> TokenStream2
+{
+    let mut PathToTraitGATs @ _ = match trait_object.bounds[idx] {
+        | TypeParamBound::Trait(ref it) => it.path.clone(),
+        | TypeParamBound::Lifetime(_) => unreachable!(),
+    };
+    let trait_ = PathToTraitGATs.segments.last_mut().unwrap();
+    trait_.ident = combine_trait_name_into_gats_macro(&trait_.ident);
+    trait_.arguments = PathArguments::None;
+    quote!(
+        #PathToTraitGATs! {
+            [__gat_dyn]
+            #trait_object
+        }
+    )
+}
+
+/// The second half of a `dyn Trait<Assoc<'n> = …>` type, once the metadata
+/// of the `trait` has been fetched: only the `#[gat(dyn)]` traits can be
+/// used as trait objects.
+pub(in super)
+fn trait_object (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let (metadata, mut trait_object) = Parser::parse2(
+        |input: ParseStream<'_>| Ok((
+            {
+                let metadata;
+                braced!(metadata in input);
+                metadata.parse::<gat::TraitMetadata>()?
+            },
+            input.parse::<TypeTraitObject>()?,
+        )),
+        input,
+    )?;
+    let idx = main_trait_of(&trait_object).unwrap();
+    let trait_bound = match trait_object.bounds[idx] {
+        | TypeParamBound::Trait(ref it) => it,
+        | TypeParamBound::Lifetime(_) => unreachable!(),
+    };
+    let TraitName @ _ = &metadata.trait_.ident;
+    if metadata.dyn_.is_none() {
+        bail! {
+            &format!(
+                "`{0}` cannot be used as a trait object: its definition needs \
+                to be annotated with `#[gat(dyn)]` rather than `#[gat]` (for \
+                its GATs to be object-safe)",
+                TraitName,
+            )
+            => trait_bound,
+        }
+    }
+    if let PathArguments::AngleBracketed(ref it) =
+        trait_bound.path.segments.last().unwrap().arguments
+    {
+        for GatBinding { ident, .. } in drain_gat_bindings(&mut it.args.clone())
+        {
+            if metadata.gats().any(|gat| gat.ident == ident).not() {
+                bail! {
+                    &format!("`{}` has no GAT named `{}`", TraitName, ident)
+                    => ident,
+                }
+            }
+        }
+    }
+    handle_dyn_trait_bounds(&mut trait_object.bounds)?;
+    Ok(trait_object.into_token_stream())
 }
 
 // Since `adjugate`'s visitor will call the above for any encountered type
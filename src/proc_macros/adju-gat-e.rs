@@ -10,16 +10,258 @@ fn adjugate (
     mut input: Item,
 ) -> Item
 {
-    visit_mut::VisitMut::visit_item_mut(
-        &mut ApplyGatToEachTypePathOccurrence,
-        &mut input,
-    );
+    let mut visitor = ApplyGatToEachTypePathOccurrence::default();
+    visit_mut::VisitMut::visit_item_mut(&mut visitor, &mut input);
+    // The type paths in error are stubbed: still emit the item.
+    if let Some(err) = visitor.errors {
+        utils::recover(err);
+    }
     input
 }
 
-struct ApplyGatToEachTypePathOccurrence;
+/// Same as `adjugate()`, but for a single type (_e.g._, the `qself` of a
+/// `Gat!` path).
+pub(in super)
+fn adjugate_type (
+    type_: &'_ mut Type,
+)
+{
+    visit_mut::VisitMut::visit_type_mut(
+        &mut ApplyGatToEachTypePathOccurrence::default(),
+        type_,
+    );
+}
+
+#[derive(Default)]
+struct ApplyGatToEachTypePathOccurrence {
+    /// The trait bounds of the generic type parameters in scope (innermost
+    /// last), to resolve the `I::Assoc<…>` shorthand.
+    scopes: Vec<Vec<(Ident, Vec<Path>)>>,
+    /// The predicates stemming from the `Trait<Assoc<'a> : Bounds>` sugar,
+    /// to be added to the `where` clause of the generics being visited.
+    extra_predicates: Vec<WherePredicate>,
+    errors: Option<Error>,
+}
+
+impl ApplyGatToEachTypePathOccurrence {
+    fn extend_extra_predicates (
+        self: &'_ mut Self,
+        extra_predicates: Result<Vec<WherePredicate>>,
+    )
+    {
+        match extra_predicates {
+            | Ok(it) => self.extra_predicates.extend(it),
+            | Err(err) => match self.errors {
+                | Some(ref mut errors) => errors.combine(err),
+                | ref mut it @ None => *it = Some(err),
+            },
+        }
+    }
+
+    fn push_scope (
+        self: &'_ mut Self,
+        generics: &'_ Generics,
+    )
+    {
+        let mut scope: Vec<(Ident, Vec<Path>)> =
+            generics
+                .type_params()
+                .map(|it| (it.ident.clone(), vec![]))
+                .collect()
+        ;
+        let mut add_bounds = |
+            ty_param: &'_ Ident,
+            bounds: &'_ Punctuated<TypeParamBound, Token![+]>,
+        | {
+            let &mut (_, ref mut paths) =
+                match scope.iter_mut().find(|(it, _)| it == ty_param) {
+                    | Some(it) => it,
+                    | None => return,
+                }
+            ;
+            for bound in bounds {
+                let mut path = match *bound {
+                    | TypeParamBound::Trait(TraitBound {
+                        modifier: TraitBoundModifier::None,
+                        ref path,
+                        ..
+                    }) => path.clone(),
+                    | _ => continue,
+                };
+                // Skip the auto traits, lest `I : LendingIterator + Send` be
+                // deemed ambiguous.
+                if is_auto_trait(&path) {
+                    continue;
+                }
+                // `Trait<…, Assoc = …>` becomes `Trait<…>`.
+                if let PathArguments::AngleBracketed(ref mut it) =
+                    path.segments.last_mut().unwrap().arguments
+                {
+                    it.args = mem::take(&mut it.args).into_iter().filter(|arg| {
+                        matches!(
+                            *arg,
+                            GenericArgument::Binding { .. }
+                            | GenericArgument::Constraint { .. }
+                        ).not()
+                    }).collect();
+                }
+                let path_str = path.to_token_stream().to_string();
+                if paths.iter().all(|it| {
+                    it.to_token_stream().to_string() != path_str
+                })
+                {
+                    paths.push(path);
+                }
+            }
+        };
+        for ty_param in generics.type_params() {
+            add_bounds(&ty_param.ident, &ty_param.bounds);
+        }
+        for predicate in generics.where_clause.iter().flat_map(|it| {
+            &it.predicates
+        })
+        {
+            if let WherePredicate::Type(PredicateType {
+                bounded_ty: Type::Path(TypePath { qself: None, ref path }),
+                ref bounds,
+                ..
+            }) = *predicate
+            {
+                if let Some(ty_param) = path.get_ident() {
+                    add_bounds(ty_param, bounds);
+                }
+            }
+        }
+        self.scopes.push(scope);
+    }
+
+    /// `I::Assoc<…>…` becomes `<I as Trait>::Assoc<…>…`, provided `I` has
+    /// exactly one trait bound in scope.
+    fn qualify_shorthand (
+        self: &'_ Self,
+        type_path: &'_ mut TypePath,
+    ) -> Result<()>
+    {
+        let path = match *type_path {
+            | TypePath { qself: None, ref path }
+                if path.leading_colon.is_none()
+                && path.segments.len() >= 2
+                && matches!(path.segments[0].arguments, PathArguments::None)
+                && matches!(
+                    path.segments[1].arguments,
+                    PathArguments::AngleBracketed { .. },
+                )
+            => path,
+            | _ => return Ok(()),
+        };
+        let ty_param = &path.segments[0].ident;
+        let bounds =
+            match
+                self.scopes
+                    .iter()
+                    .rev()
+                    .flatten()
+                    .find(|(it, _)| it == ty_param)
+            {
+                | Some((_, bounds)) => bounds,
+                | None => return Ok(()),
+            }
+        ;
+        let Trait @ _ = match &bounds[..] {
+            | [] => return Ok(()),
+            | [Trait] => Trait,
+            | _ => bail! {
+                &format!(
+                    "ambiguous associated type: `{}` has several (non-auto) \
+                    trait bounds, so the trait providing `{}` cannot be \
+                    inferred. \
+                    Qualify it, as in `<{} as Trait>::{}<…>`",
+                    ty_param, path.segments[1].ident,
+                    ty_param, path.segments[1].ident,
+                ) => path,
+            },
+        };
+        let each_assoc_segment = path.segments.iter().skip(1);
+        *type_path = parse_quote!(
+            <#ty_param as #Trait> #(:: #each_assoc_segment)*
+        );
+        Ok(())
+    }
+}
+
+/// The `I::Assoc<…>` shorthand needs to be qualified before the actual
+/// visit (_e.g._, before `add_ty_generic_gat_bounds()` inspects the
+/// signatures), hence this pre-pass, performed when entering each scope.
+///
+/// It does not subrecurse into the nested scopes, which get their own.
+struct QualifyShorthands<'visitor> /* = */ (
+    &'visitor mut ApplyGatToEachTypePathOccurrence,
+);
+
+impl visit_mut::VisitMut for QualifyShorthands<'_> {
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_impl_item_method_mut (
+        self: &'_ mut Self,
+        _: &'_ mut ImplItemMethod,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_trait_item_method_mut (
+        self: &'_ mut Self,
+        _: &'_ mut TraitItemMethod,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_type_path_mut (
+        self: &'_ mut Self,
+        type_path: &'_ mut TypePath,
+    )
+    {
+        visit_mut::visit_type_path_mut(self, type_path); // subrecurse
+        if let Err(err) = self.0.qualify_shorthand(type_path) {
+            *type_path = utils::stub_type_path(type_path);
+            match self.0.errors {
+                | Some(ref mut errors) => errors.combine(err),
+                | ref mut it @ None => *it = Some(err),
+            }
+        }
+    }
+}
 
 impl visit_mut::VisitMut for ApplyGatToEachTypePathOccurrence {
+    fn visit_item_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        item: &'_ mut Item,
+    )
+    {
+        let generics = match *item {
+            | Item::Enum(ItemEnum { ref generics, .. })
+            | Item::Fn(ItemFn { sig: Signature { ref generics, .. }, .. })
+            | Item::Impl(ItemImpl { ref generics, .. })
+            | Item::Struct(ItemStruct { ref generics, .. })
+            | Item::Trait(ItemTrait { ref generics, .. })
+            | Item::Type(ItemType { ref generics, .. })
+            | Item::Union(ItemUnion { ref generics, .. })
+            => generics,
+            | _ => return visit_mut::visit_item_mut(self, item), // subrecurse
+        };
+        self.push_scope(generics);
+        visit_mut::visit_item_mut(&mut QualifyShorthands(self), item);
+        visit_mut::visit_item_mut(self, item); // subrecurse
+        self.scopes.pop();
+    }
+
     fn visit_type_mut (
         self: &'_ mut ApplyGatToEachTypePathOccurrence,
         type_: &'_ mut Type,
@@ -37,6 +279,11 @@ impl visit_mut::VisitMut for ApplyGatToEachTypePathOccurrence {
                     | Err(()) => {},
                 }
             },
+            | Type::TraitObject(ref trait_object) => {
+                if let Some(deferred) = Gat::handle_trait_object(trait_object) {
+                    *type_ = Type::Verbatim(deferred);
+                }
+            },
             | Type::ImplTrait(ref impl_trait) => {
                 match Gat::Gat(Gat::Input::TypeImpl(impl_trait.clone())) {
                     | Ok(modified_type_path) => {
@@ -51,13 +298,57 @@ impl visit_mut::VisitMut for ApplyGatToEachTypePathOccurrence {
         }
     }
 
+    fn visit_item_fn_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        item_fn: &'_ mut ItemFn,
+    )
+    {
+        add_ty_generic_gat_bounds(&mut item_fn.sig);
+        visit_mut::visit_item_fn_mut(self, item_fn); // subrecurse
+    }
+
+    fn visit_trait_item_method_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        method: &'_ mut TraitItemMethod,
+    )
+    {
+        self.push_scope(&method.sig.generics);
+        visit_mut::visit_trait_item_method_mut(
+            &mut QualifyShorthands(self),
+            method,
+        );
+        add_ty_generic_gat_bounds(&mut method.sig);
+        visit_mut::visit_trait_item_method_mut(self, method); // subrecurse
+        self.scopes.pop();
+    }
+
+    fn visit_impl_item_method_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        method: &'_ mut ImplItemMethod,
+    )
+    {
+        self.push_scope(&method.sig.generics);
+        visit_mut::visit_impl_item_method_mut(
+            &mut QualifyShorthands(self),
+            method,
+        );
+        make_ty_generic_gat_lifetimes_early_bound(&mut method.sig);
+        visit_mut::visit_impl_item_method_mut(self, method); // subrecurse
+        self.scopes.pop();
+    }
+
     fn visit_type_param_mut (
         self: &'_ mut ApplyGatToEachTypePathOccurrence,
         type_param: &'_ mut TypeParam,
     )
     {
         visit_mut::visit_type_param_mut(self, type_param); // subrecurse
-        crate::Gat::handle_trait_bounds(&mut type_param.bounds);
+        let TypeParam_ @ _ = &type_param.ident;
+        let extra_predicates = crate::Gat::handle_trait_bounds(
+            &mut type_param.bounds,
+            Some((&parse_quote!( #TypeParam_ ), None)),
+        );
+        self.extend_extra_predicates(extra_predicates);
     }
 
     fn visit_predicate_type_mut (
@@ -66,7 +357,43 @@ impl visit_mut::VisitMut for ApplyGatToEachTypePathOccurrence {
     )
     {
         visit_mut::visit_predicate_type_mut(self, predicate_type); // subrecurse
-        crate::Gat::handle_trait_bounds(&mut predicate_type.bounds);
+        let extra_predicates = crate::Gat::handle_trait_bounds(
+            &mut predicate_type.bounds,
+            Some((
+                &predicate_type.bounded_ty,
+                predicate_type.lifetimes.as_ref(),
+            )),
+        );
+        self.extend_extra_predicates(extra_predicates);
+    }
+
+    fn visit_generics_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        generics: &'_ mut Generics,
+    )
+    {
+        let outer_predicates = mem::take(&mut self.extra_predicates);
+        visit_mut::visit_generics_mut(self, generics); // subrecurse
+        let extra_predicates =
+            mem::replace(&mut self.extra_predicates, outer_predicates)
+        ;
+        if extra_predicates.is_empty().not() {
+            generics.make_where_clause().predicates.extend(extra_predicates);
+        }
+    }
+
+    fn visit_trait_item_type_mut (
+        self: &'_ mut ApplyGatToEachTypePathOccurrence,
+        trait_item_type: &'_ mut TraitItemType,
+    )
+    {
+        visit_mut::visit_trait_item_type_mut(self, trait_item_type); // subrecurse
+        // There is no `where` clause to add the GAT constraints to, here.
+        let extra_predicates = crate::Gat::handle_trait_bounds(
+            &mut trait_item_type.bounds,
+            None,
+        );
+        self.extend_extra_predicates(extra_predicates);
     }
 
     fn visit_item_trait_mut (
@@ -75,6 +402,135 @@ impl visit_mut::VisitMut for ApplyGatToEachTypePathOccurrence {
     )
     {
         visit_mut::visit_item_trait_mut(self, item_trait); // subrecurse
-        crate::Gat::handle_trait_bounds(&mut item_trait.supertraits);
+        let outer_predicates = mem::take(&mut self.extra_predicates);
+        let extra_predicates = crate::Gat::handle_trait_bounds(
+            &mut item_trait.supertraits,
+            Some((&parse_quote!( Self ), None)),
+        );
+        self.extend_extra_predicates(extra_predicates);
+        let extra_predicates =
+            mem::replace(&mut self.extra_predicates, outer_predicates)
+        ;
+        if extra_predicates.is_empty().not() {
+            item_trait.generics.make_where_clause().predicates.extend(
+                extra_predicates
+            );
+        }
+    }
+}
+
+/// Contrary to lifetime GATs, type-or-`const`-generic GATs are not implied by
+/// the trait (no `for<T>` quantification), so the `Ty : TraitඞAssoc<…>` bounds
+/// have to be added to the signatures mentioning them.
+///
+/// Not done for `impl` methods, since the `where` clause would then shadow the
+/// actual `impl` of the helper trait, preventing the normalization of these
+/// types.
+fn add_ty_generic_gat_bounds (
+    sig: &'_ mut Signature,
+)
+{
+    let mut each_bound = CollectTyGenericGatBounds {
+        bounds: vec![],
+        stub_elided: true,
+    };
+    visit_mut::visit_signature_mut(&mut each_bound, sig);
+    let mut seen = vec![];
+    for predicate in each_bound.bounds {
+        let predicate_str = predicate.to_token_stream().to_string();
+        if seen.contains(&predicate_str) {
+            continue;
+        }
+        seen.push(predicate_str);
+        sig.generics.make_where_clause().predicates.push(predicate);
+    }
+}
+
+/// The bounds added by `add_ty_generic_gat_bounds()` to the trait methods
+/// make the lifetimes they mention early-bound, so the `impl` methods have to
+/// follow suit (lest E0195 ensue), hence the dummy `'lt : 'lt` bounds.
+fn make_ty_generic_gat_lifetimes_early_bound (
+    sig: &'_ mut Signature,
+)
+{
+    let mut each_bound = CollectTyGenericGatBounds {
+        bounds: vec![],
+        stub_elided: false,
+    };
+    visit_mut::visit_signature_mut(&mut each_bound, sig);
+    let mut mentioned = CollectLifetimes(vec![]);
+    each_bound.bounds.iter_mut().for_each(|predicate| {
+        visit_mut::visit_where_predicate_mut(&mut mentioned, predicate);
+    });
+    let each_lifetime =
+        sig .generics
+            .lifetimes()
+            .map(|it| it.lifetime.clone())
+            .filter(|lt| mentioned.0.contains(lt))
+            .collect::<Vec<_>>()
+    ;
+    for lt in each_lifetime {
+        sig.generics.make_where_clause().predicates.push(parse_quote!(
+            #lt : #lt
+        ));
+    }
+}
+
+struct CollectTyGenericGatBounds {
+    bounds: Vec<WherePredicate>,
+    /// Whether the paths whose bound would mention a `'_` lifetime are to be
+    /// stubbed (and reported), since such a bound can't be written.
+    stub_elided: bool,
+}
+
+impl visit_mut::VisitMut for CollectTyGenericGatBounds {
+    fn visit_type_path_mut (
+        self: &'_ mut CollectTyGenericGatBounds,
+        type_path: &'_ mut TypePath,
+    )
+    {
+        visit_mut::visit_type_path_mut(self, type_path); // subrecurse
+        match type_path.qself {
+            | Some(QSelf { position, as_token: Some(_), .. })
+                if type_path.path.segments.len() == position + 1
+            => {},
+            | _ => return,
+        }
+        match type_path.path.segments.last().unwrap().arguments {
+            | PathArguments::AngleBracketed(ref it)
+                if count_lifetime_args(&it.args) < it.args.len()
+            => {},
+            | _ => return,
+        }
+        // `<Ty as path::to::TraitඞAssoc<…>>::T`
+        let TypePath { qself, path } =
+            match Gat::Gat::<()>(Gat::Input::TypePath(type_path.clone()))
+                    .ok()
+                    .and_then(|it| parse2(it).ok())
+            {
+                | Some(it) => it,
+                | None => return,
+            }
+        ;
+        let Ty @ _ = qself.unwrap().ty;
+        let leading_colon = &path.leading_colon;
+        let each_segment = path.segments.iter().take(path.segments.len() - 1);
+        let mut bound: WherePredicate = parse_quote!(
+            #Ty : #leading_colon #(#each_segment)::*
+        );
+        if self.stub_elided {
+            let mut lifetimes = CollectLifetimes(vec![]);
+            visit_mut::visit_where_predicate_mut(&mut lifetimes, &mut bound);
+            if let Some(elided) = lifetimes.0.iter().find(|it| it.ident == "_") {
+                utils::recover(Error::new_spanned(elided,
+                    "elided lifetimes are not supported by the GATs with type \
+                    (or `const`) parameters, in signatures: name it, as in \
+                    `fn f<'a, T> (&'a self) -> Self::Assoc<'a, T>`",
+                ));
+                *type_path = utils::stub_type_path(type_path);
+                return;
+            }
+        }
+        self.bounds.push(bound);
     }
 }
@@ -1,5 +1,7 @@
 use super::*;
 
+mod auto_outlives;
+mod self_bounds;
 mod trait_def;
 mod trait_impl;
 mod trait_use;
@@ -10,14 +12,29 @@ fn gat (
     input: TokenStream2,
 ) -> Result<TokenStream2>
 {
+    // `#[gat(Self : Trait…)]`, on any kind of item.
+    if matches!(
+        attrs.clone().into_iter().next(),
+        Some(TT::Ident(ident)) if ident == "Self"
+    )
+    {
+        let self_bounds: self_bounds::SelfBounds = parse2(attrs)?;
+        return self_bounds::defer(self_bounds, vec![], parse2(input)?);
+    }
     match parse2(input)? {
         | Item::Trait(item_trait) => {
-            let _: parse::Nothing = parse2(attrs)?;
-            trait_def::handle(item_trait)
+            // Malformed args are ignored, so as to still emit the trait.
+            let trait_def::Args { dyn_, auto_outlives, krate } =
+                parse2(attrs).unwrap_or_else(|err| {
+                    utils::recover(err);
+                    trait_def::Args::default()
+                })
+            ;
+            trait_def::handle(item_trait, dyn_, auto_outlives, krate)
         },
         | Item::Impl(item_impl) => {
-            let _: parse::Nothing = parse2(attrs)?;
-            trait_impl::handle(item_impl)
+            let _: Option<Token![dyn]> = parse2(attrs.clone())?;
+            trait_impl::defer(item_impl, attrs)
         },
         | Item::Use(item_use) => {
             let assoc_types = Punctuated::<Ident, Token![,]>::parse_terminated.parse2(attrs)?;
@@ -28,27 +45,271 @@ fn gat (
     .map(utils::mb_file_expanded)
 }
 
-//. A lifetime-generic associated type.
+/// `{ <TraitMetadata> } { <attrs> } <impl…>`, as emitted by `TraitඞGATs!`
+/// on behalf of `trait_impl::defer()`.
+pub(in super)
+fn gat_impl (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let (metadata, dyn_, item_impl) = Parser::parse2(
+        |input: ParseStream<'_>| Ok((
+            {
+                let metadata;
+                braced!(metadata in input);
+                metadata.parse::<TraitMetadata>()?
+            },
+            {
+                let attrs;
+                braced!(attrs in input);
+                attrs.parse::<Option<Token![dyn]>>()?
+            },
+            input.parse::<ItemImpl>()?,
+        )),
+        input,
+    )?;
+    trait_impl::handle(item_impl, dyn_, metadata)
+        .map(utils::mb_file_expanded)
+}
+
+/// `{ <TraitMetadata> } <use…>`, as emitted by `TraitඞGATs!` on behalf of
+/// a `#[gat] use` not listing the GATs.
+pub(in super)
+fn gat_use (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let (metadata, item_use) = Parser::parse2(
+        |input: ParseStream<'_>| Ok((
+            {
+                let metadata;
+                braced!(metadata in input);
+                metadata.parse::<TraitMetadata>()?
+            },
+            input.parse::<ItemUse>()?,
+        )),
+        input,
+    )?;
+    trait_use::handle_fetched(metadata, item_use)
+        .map(utils::mb_file_expanded)
+}
+
+/// `{ <TraitMetadata> } { Self : … } { <TraitMetadata> }… <item>`, as
+/// emitted by the `TraitඞGATs!` of each trait in a `#[gat(Self : …)]`.
+pub(in super)
+fn gat_self (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    self_bounds::gat_self(input)
+        .map(utils::mb_file_expanded)
+}
+
+/// What a `#[gat] impl` (or a nested `Gat!` path) gets to know about the
+/// `#[gat] trait`: its generics, and its GATs (with their generics, bounds,
+/// and defaults, if any), as well as the supertrait of its inherited GATs,
+/// when given other generic args than the `trait`'s own.
+///
+/// It is conveyed as a (stripped down) `trait` definition.
+pub(in crate)
+struct TraitMetadata {
+    pub(in crate) dyn_: Option<Token![dyn]>,
+    pub(in crate) trait_: ItemTrait,
+}
+
+impl TraitMetadata {
+    pub(in crate)
+    fn gats (self: &'_ Self)
+      -> impl '_ + Iterator<Item = &'_ TraitItemType>
+    {
+        self.trait_.items.iter().filter_map(|item| match *item {
+            | TraitItem::Type(ref it) => Some(it),
+            | _ => None,
+        })
+    }
+}
+
+impl Parse for TraitMetadata {
+    fn parse (input: ParseStream<'_>)
+      -> Result<TraitMetadata>
+    {
+        Ok(TraitMetadata {
+            dyn_: input.parse()?,
+            trait_: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for TraitMetadata {
+    fn to_tokens (self: &'_ Self, out: &'_ mut TokenStream2)
+    {
+        let Self { dyn_, trait_ } = self;
+        dyn_.to_tokens(out);
+        trait_.to_tokens(out);
+    }
+}
+
+/// The `#[gat(covariant)]` marker of a GAT, which makes the trait require
+/// (and the `#[gat] impl`s provide) a covariance witness (see
+/// `upcast_signature()`).
+///
+/// Returns whether the attribute is such a marker (erroring on any other
+/// `#[gat(…)]` attribute).
+fn is_covariant_marker (attr: &'_ Attribute)
+  -> Result<bool>
+{
+    if attr.path.is_ident("gat").not() {
+        return Ok(false);
+    }
+    let marker: Ident = attr.parse_args().map_err(|err| {
+        Error::new(err.span(), "expected `#[gat(covariant)]`")
+    })?;
+    if marker != "covariant" {
+        bail!("expected `covariant`" => marker);
+    }
+    Ok(true)
+}
+
+/// `type Assoc<'lt>` yields
+/// `fn upcast_assoc<'short, 'long : 'short> (it: Self::Assoc<'long>) -> Self::Assoc<'short>`,
+/// the signature of the covariance witness of a `#[gat(covariant)]` GAT.
+///
+/// Its body, in the `impl`s, is the mere identity, which thus fails to compile
+/// when the type of the GAT is not actually covariant.
+fn upcast_signature (
+    Assoc: &'_ Ident,
+    generics: &'_ Generics,
+) -> TokenStream2
+{
+    let mut snake_case = String::from("upcast");
+    for c in Assoc.to_string().chars() {
+        if c.is_uppercase() {
+            snake_case.push('_');
+        }
+        snake_case.extend(c.to_lowercase());
+    }
+    let upcast_assoc = Ident::new(&snake_case, Assoc.span());
+    let several = generics.lifetimes().nth(1).is_some();
+    let mut lifetime_params: Vec<GenericParam> = vec![];
+    let mut other_params: Vec<GenericParam> = vec![];
+    let (mut long_args, mut short_args) = (vec![], vec![]);
+    for param in &generics.params {
+        match *param {
+            | GenericParam::Lifetime(ref it) => {
+                let suffix = if several {
+                    lifetime_params.len().to_string()
+                } else {
+                    String::new()
+                };
+                let span = it.lifetime.span();
+                let short = Lifetime::new(&format!("'short{}", suffix), span);
+                let long = Lifetime::new(&format!("'long{}", suffix), span);
+                lifetime_params.push(parse_quote!( #short ));
+                lifetime_params.push(parse_quote!( #long : #short ));
+                short_args.push(quote!( #short ));
+                long_args.push(quote!( #long ));
+            },
+            | GenericParam::Type(TypeParam { ref ident, .. })
+            | GenericParam::Const(ConstParam { ref ident, .. })
+            => {
+                other_params.push(param.clone());
+                short_args.push(quote!( #ident ));
+                long_args.push(quote!( #ident ));
+            },
+        }
+    }
+    let each_param = lifetime_params.iter().chain(&other_params);
+    quote!(
+        fn #upcast_assoc <#(#each_param),*> (
+            it: Self::#Assoc<#(#long_args),*>,
+        ) -> Self::#Assoc<#(#short_args),*>
+        where
+            Self : Sized,
+    )
+}
+
+//. A (lifetime-)generic associated type.
 struct LGat {
     attrs: Vec<Attribute>,
     ident: Ident,
     bounds: Punctuated<TypeParamBound, Token![+]>,
     generic_lifetimes: Vec<Lifetime>,
-    super_types: Vec<(Lifetime, Option<Type>)>,
+    /// Since these cannot be `for<…>`-quantified, the resulting helper trait
+    /// cannot be a supertrait of the main one.
+    generic_tys_and_consts: Vec<GenericParam>,
+    super_types: SuperTypes,
+    /// Predicates which cannot be encoded as implicit bounds, and which are
+    /// thus carried over as actual `where` clauses: on the helper trait and
+    /// its impls when not `for<…>`-quantified, and only on the helper impls
+    /// otherwise (see `trait_def`).
+    where_predicates: Vec<WherePredicate>,
     /// The actual type fed in an impl trait,
     value: Option<Type>,
 }
 
+/// The `where SuperTy : 'lt` bounds of a GAT.
+type SuperTypes = Vec<(Lifetime, Option<Type>)>;
+
+/// The generics of a GAT, as split by `LGat::parse_generics()`.
+type ParsedGenerics = (
+    /* generic_lifetimes: */ Vec<Lifetime>,
+    /* generic_tys_and_consts: */ Vec<GenericParam>,
+    /* super_types: */ SuperTypes,
+    /* where_predicates: */ Vec<WherePredicate>,
+);
+
 impl LGat {
+    /// `parse_generics()`, but, on error, the GAT is stubbed (see `stub()`)
+    /// so that the rest of the trait (or `impl`) still gets emitted, and the
+    /// error is reported alongside it (see `utils::recover()`).
+    fn parse_generics_or_stub (
+        generics: Generics,
+    ) -> ParsedGenerics
+    {
+        let mut stub = generics.clone();
+        match Self::parse_generics(generics) {
+            | Ok(it) => it,
+            | Err(err) => {
+                utils::recover(err);
+                Self::stub(&mut stub);
+                Self::parse_generics(stub)
+                    .expect("stubbed GAT generics should always be supported")
+            },
+        }
+    }
+
+    /// Drops the `where` clauses of the GAT, as well as the attributes and
+    /// defaults of its params: what remains is always supported.
+    fn stub (generics: &'_ mut Generics)
+    {
+        generics.where_clause = None;
+        for param in &mut generics.params {
+            match *param {
+                | GenericParam::Lifetime(ref mut it) => {
+                    it.attrs = vec![];
+                },
+                | GenericParam::Type(ref mut it) => {
+                    it.attrs = vec![];
+                    it.eq_token = None;
+                    it.default = None;
+                },
+                | GenericParam::Const(ref mut it) => {
+                    it.attrs = vec![];
+                    it.eq_token = None;
+                    it.default = None;
+                },
+            }
+        }
+    }
+
     fn parse_generics (
         generics: Generics,
-    ) -> Result<(
-            /* generic_lifetimes: */ Vec<Lifetime>,
-            /* super_types: */ Vec<(Lifetime, Option<Type>)>,
-        )>
+    ) -> Result<ParsedGenerics>
     {
         let mut generic_lifetimes = vec![];
+        let mut generic_tys_and_consts = vec![];
         let mut super_types = vec![];
+        let mut where_predicates = vec![];
         for generic in generics.params {
             match generic {
                 | GenericParam::Lifetime(LifetimeDef {
@@ -62,15 +323,36 @@ impl LGat {
                             "unsupported" => attr,
                         }
                     }
-                    if let Some(bound) = bounds.first() {
-                        bail! {
-                            "unsupported" => bound,
-                        }
+                    // `'a : 'b` is encoded as `&'a () : 'b`, so that it
+                    // becomes the `&'b &'a ()` implicit bound.
+                    for bound in bounds {
+                        super_types.push((bound, Some(parse_quote!(
+                            &#lifetime ()
+                        ))));
                     }
                     generic_lifetimes.push(lifetime);
                 },
-                | _ => bail! {
-                    "non-lifetime GATs are not supported" => generic,
+                | GenericParam::Type(TypeParam {
+                    ref attrs,
+                    ref eq_token,
+                    ..
+                })
+                | GenericParam::Const(ConstParam {
+                    ref attrs,
+                    ref eq_token,
+                    ..
+                }) => {
+                    if let Some(attr) = attrs.first() {
+                        bail! {
+                            "unsupported" => attr,
+                        }
+                    }
+                    if let Some(eq_) = eq_token {
+                        bail! {
+                            "defaulted GAT parameters are not supported" => eq_,
+                        }
+                    }
+                    generic_tys_and_consts.push(generic);
                 },
             }
         }
@@ -84,19 +366,70 @@ impl LGat {
                     colon_token: _,
                     bounds,
                 }) => {
-                    if let Some(for_) =
-                        lifetimes.into_iter().flat_map(|l| l.lifetimes).next()
+                    // `for<'x>` quantifications that do not involve `'x` are
+                    // just dropped.
+                    let hr_lifetimes = {
+                        let mut mentioned = CollectLifetimes(vec![]);
+                        visit_mut::visit_type_mut(
+                            &mut mentioned,
+                            &mut bounded_ty.clone(),
+                        );
+                        bounds.iter().for_each(|bound| {
+                            visit_mut::visit_type_param_bound_mut(
+                                &mut mentioned,
+                                &mut bound.clone(),
+                            )
+                        });
+                        lifetimes
+                            .into_iter()
+                            .flat_map(|l| l.lifetimes)
+                            .filter(|l| mentioned.0.contains(&l.lifetime))
+                            .collect::<Punctuated<_, Token![,]>>()
+                    };
+                    let for_lifetimes: Option<BoundLifetimes> =
+                        if hr_lifetimes.is_empty() {
+                            None
+                        } else {
+                            Some(parse_quote!( for<#hr_lifetimes> ))
+                        }
+                    ;
+                    let (lifetime_bounds, trait_bounds): (Vec<_>, Vec<_>) =
+                        bounds.into_iter().partition(|bound| matches!(
+                            *bound, TypeParamBound::Lifetime(_)
+                        ))
+                    ;
+                    // Trait bounds cannot be encoded as implied bounds: they
+                    // are carried over as actual `where` clauses.
+                    if trait_bounds.is_empty().not() {
+                        where_predicates.push(parse_quote!(
+                            #for_lifetimes #bounded_ty : #(#trait_bounds)+*
+                        ));
+                    }
+                    if for_lifetimes.is_some()
+                    && lifetime_bounds.is_empty().not()
                     {
-                        bail! {
-                            "higher-order lifetimes are not supported" => for_
+                        if generic_tys_and_consts.is_empty() {
+                            bail! {
+                                "higher-ranked outlives predicates are not \
+                                supported on lifetime GATs: since these are \
+                                `for<…>`-quantified by the trait, their \
+                                `where` clauses have to be encoded as implied \
+                                bounds (such as `&'lt Ty`), and no type can \
+                                carry its own `for<'x>` quantification while \
+                                implying its bounds"
+                                => hr_lifetimes.first(),
+                            }
                         }
+                        // Not quantified: an actual `where` clause will do.
+                        where_predicates.push(parse_quote!(
+                            #for_lifetimes #bounded_ty : #(#lifetime_bounds)+*
+                        ));
+                        continue;
                     }
-                    for bound in bounds {
+                    for bound in lifetime_bounds {
                         let super_lt = match bound {
                             | TypeParamBound::Lifetime(lt) => lt,
-                            | _ => bail! {
-                                "unsupported" => bound,
-                            },
+                            | _ => unreachable!(),
                         };
                         let super_lt_str = &super_lt.ident.to_string();
                         if  generic_lifetimes
@@ -124,12 +457,36 @@ impl LGat {
                         }
                     }
                 },
+                | WherePredicate::Lifetime(PredicateLifetime {
+                    lifetime,
+                    colon_token: _,
+                    bounds,
+                }) => {
+                    let is_gat_lifetime = |lt: &Lifetime| {
+                        generic_lifetimes.iter().any(|it| it.ident == lt.ident)
+                    };
+                    for bound in bounds {
+                        if is_gat_lifetime(&lifetime).not()
+                        && is_gat_lifetime(&bound).not()
+                        {
+                            bail! {
+                                "expected a GAT-generic lifetime" => bound,
+                            }
+                        }
+                        // Same encoding as for `<'a : 'b>` above.
+                        super_types.push((bound, Some(parse_quote!(
+                            &#lifetime ()
+                        ))));
+                    }
+                },
                 | _ => bail!("unsupported `where predicate`" => predicate),
             }
         }
         Ok((
             generic_lifetimes,
+            generic_tys_and_consts,
             super_types,
+            where_predicates,
         ))
     }
 }
@@ -157,10 +514,12 @@ impl visit_mut::VisitMut
         // 1. subrecurse
         visit_mut::visit_type_path_mut(self, type_path);
 
-        // 2. Handle the `Self::` case.
+        // 2. Handle the `Self::` case (the GAT may be followed by nested
+        //    associated items: `Self::Assoc<'_>::Nested`).
         if  type_path.path.segments.first().unwrap().ident == "Self"
+        &&  type_path.path.segments.len() >= 2
         &&  matches!(
-                type_path.path.segments.last().unwrap().arguments,
+                type_path.path.segments[1].arguments,
                 PathArguments::AngleBracketed { .. }
             )
         {
@@ -190,3 +549,311 @@ impl visit_mut::VisitMut
         }
     }
 }
+
+/// `Self::Assoc<…>` becomes `<Self as Supertrait>::Assoc<…>`, for the one
+/// (non-marker) supertrait listed in the header of the `trait`, when `Assoc` is
+/// not a GAT of the `trait` itself.
+struct ReplaceSelfAssocLtWithSelfAsSupertraitAssocLt {
+    own_gats: Vec<Ident>,
+    supertraits: Vec<Path>,
+    inherited_gats: Vec<(Ident, Path)>,
+    errors: Option<Error>,
+}
+
+impl visit_mut::VisitMut
+    for ReplaceSelfAssocLtWithSelfAsSupertraitAssocLt
+{
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_type_path_mut (
+        self: &'_ mut Self,
+        type_path: &'_ mut TypePath,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_type_path_mut(self, type_path);
+
+        // 2. Handle the `Self::Assoc<…>` case.
+        if  type_path.qself.is_some()
+        ||  type_path.path.segments.first().unwrap().ident != "Self"
+        ||  type_path.path.segments.len() < 2
+        ||  matches!(
+                type_path.path.segments[1].arguments,
+                PathArguments::AngleBracketed { .. },
+            ).not()
+        ||  self.own_gats.contains(&type_path.path.segments[1].ident)
+        {
+            return;
+        }
+        let Assoc @ _ = &type_path.path.segments[1].ident;
+        let Supertrait @ _ = match self.supertraits[..] {
+            | [] => return, // Let it error as a GAT of the `trait` itself.
+            | [ref it] => it,
+            | _ => {
+                let err = Error::new_spanned(
+                    &type_path,
+                    format_args!(
+                        "ambiguous associated type: `{0}` is not a GAT of \
+                        this trait, and it has several (non-auto) \
+                        supertraits. \
+                        Qualify it, as in `<Self as Trait>::{0}<…>`",
+                        Assoc,
+                    ),
+                );
+                *type_path = utils::stub_type_path(type_path);
+                match self.errors {
+                    | Some(ref mut errors) => errors.combine(err),
+                    | ref mut it @ None => *it = Some(err),
+                }
+                return;
+            },
+        };
+        if self.inherited_gats.iter().all(|(it, _)| it != Assoc) {
+            self.inherited_gats.push((Assoc.clone(), Supertrait.clone()));
+        }
+        let each_assoc_segment = type_path.path.segments.iter().skip(1);
+        *type_path = parse_quote!(
+            <Self as #Supertrait> #(:: #each_assoc_segment)*
+        );
+    }
+}
+
+/// `#[gat(dyn)]`: `Self::Assoc<'_>` becomes
+/// `<<Self as Trait>::Assoc as TraitඞAssocඞHKT<'_>>::T` rather than
+/// `<Self as TraitඞAssoc<'_>>::T`, for the sake of object safety.
+struct ReplaceSelfAssocLtWithSelfAssocAsHkt {
+    trait_name: Ident,
+    trait_args: Punctuated<GenericArgument, Token![,]>,
+    dyn_gats: Vec<Ident>,
+}
+
+impl visit_mut::VisitMut
+    for ReplaceSelfAssocLtWithSelfAssocAsHkt
+{
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_type_mut (
+        self: &'_ mut Self,
+        type_: &'_ mut Type,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_type_mut(self, type_);
+
+        // 2. Handle the `Self::Assoc<'_>` case.
+        let (Assoc, gat_args) = match *type_ {
+            | Type::Path(TypePath { qself: None, ref path })
+                if path.segments.len() == 2
+                && path.segments[0].ident == "Self"
+                && self.dyn_gats.contains(&path.segments[1].ident)
+            => match path.segments[1].arguments {
+                | PathArguments::AngleBracketed(ref it) => (
+                    &path.segments[1].ident,
+                    it.args.clone(),
+                ),
+                | _ => return,
+            },
+            | _ => return,
+        };
+        let Self { trait_name: TraitName, trait_args, .. } = self;
+        let Trait_Assoc_HKT =
+            combine_trait_name_and_assoc_type_into_hkt(TraitName, Assoc)
+        ;
+        let mut hkt_args = gat_args;
+        Gat::merge_generic_args(&mut hkt_args, trait_args.clone());
+        *type_ = Type::Verbatim(quote!(
+            <
+                <Self as #TraitName<#trait_args>>::#Assoc
+                as
+                #Trait_Assoc_HKT<#hkt_args>
+            >::T
+        ));
+    }
+}
+
+/// Within an item taken from the trait definition (_e.g._, a default):
+/// `'trait_lt` becomes `'lt_arg`, `TraitParam` becomes `Arg`, `Self` becomes
+/// the given `Self_` type, and `Self::Assoc` becomes
+/// `<Self_ as Trait<…>>::Assoc`.
+pub(in crate)
+struct SubstituteTraitGenerics {
+    PathToTrait: Path,
+    Self_: Type,
+    lifetimes: Vec<(Ident, Lifetime)>,
+    tys_and_consts: Vec<(Ident, TokenStream2)>,
+}
+
+impl SubstituteTraitGenerics {
+    pub(in crate)
+    fn new (
+        PathToTrait: &'_ Path,
+        trait_generics: &'_ Generics,
+        Self_: Type,
+    ) -> Result<Self>
+    {
+        let mut ret = Self {
+            PathToTrait: PathToTrait.clone(),
+            Self_,
+            lifetimes: vec![],
+            tys_and_consts: vec![],
+        };
+        let no_args = Punctuated::new();
+        let trait_args = match PathToTrait.segments.last().unwrap().arguments {
+            | PathArguments::None => &no_args,
+            | PathArguments::AngleBracketed(ref it) => &it.args,
+            | PathArguments::Parenthesized(ref it) => bail! {
+                "expected `<`" => it,
+            },
+        };
+        ret.add_generics(trait_generics, trait_args);
+        Ok(ret)
+    }
+
+    /// Pairs each generic param with its (positional) arg, or its default.
+    pub(in crate)
+    fn add_generics (
+        self: &'_ mut Self,
+        generics: &'_ Generics,
+        args: &'_ Punctuated<GenericArgument, Token![,]>,
+    )
+    {
+        let mut lifetime_args = args.iter().filter_map(|arg| match *arg {
+            | GenericArgument::Lifetime(ref it) => Some(it),
+            | _ => None,
+        });
+        let mut ty_or_const_args = args.iter().filter(|arg| matches!(
+            *arg, GenericArgument::Type(_) | GenericArgument::Const(_)
+        ));
+        for param in &generics.params {
+            match *param {
+                | GenericParam::Lifetime(LifetimeDef { ref lifetime, .. }) => {
+                    if let Some(arg) = lifetime_args.next() {
+                        self.lifetimes.push((
+                            lifetime.ident.clone(),
+                            arg.clone(),
+                        ));
+                    }
+                },
+                | GenericParam::Type(TypeParam {
+                    ref ident,
+                    ref default,
+                    ..
+                }) => {
+                    let arg = match (ty_or_const_args.next(), default) {
+                        | (Some(arg), _) => arg.to_token_stream(),
+                        | (None, Some(default)) => default.to_token_stream(),
+                        | (None, None) => continue,
+                    };
+                    self.tys_and_consts.push((ident.clone(), arg));
+                },
+                | GenericParam::Const(ConstParam {
+                    ref ident,
+                    ref default,
+                    ..
+                }) => {
+                    let arg = match (ty_or_const_args.next(), default) {
+                        | (Some(arg), _) => arg.to_token_stream(),
+                        | (None, Some(default)) => default.to_token_stream(),
+                        | (None, None) => continue,
+                    };
+                    self.tys_and_consts.push((ident.clone(), arg));
+                },
+            }
+        }
+    }
+
+    fn ty_or_const_arg (self: &'_ Self, path: &'_ Path)
+      -> Option<&'_ TokenStream2>
+    {
+        let ident = path.get_ident()?;
+        self.tys_and_consts
+            .iter()
+            .find(|(param, _)| param == ident)
+            .map(|(_, arg)| arg)
+    }
+}
+
+impl visit_mut::VisitMut
+    for SubstituteTraitGenerics
+{
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_lifetime_mut (
+        self: &'_ mut Self,
+        lifetime: &'_ mut Lifetime,
+    )
+    {
+        if let Some((_, arg)) =
+            self.lifetimes.iter().find(|(param, _)| *param == lifetime.ident)
+        {
+            *lifetime = arg.clone();
+        }
+    }
+
+    fn visit_type_mut (
+        self: &'_ mut Self,
+        type_: &'_ mut Type,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_type_mut(self, type_);
+
+        // 2. Handle the `TraitParam`, `Self`, and `Self::Assoc…` cases.
+        let type_path = match *type_ {
+            | Type::Path(ref mut it) if it.qself.is_none() => it,
+            | _ => return,
+        };
+        if let Some(arg) = self.ty_or_const_arg(&type_path.path) {
+            *type_ = Type::Verbatim(arg.clone());
+            return;
+        }
+        if type_path.path.segments[0].ident != "Self" {
+            return;
+        }
+        let Self { PathToTrait, Self_, .. } = &*self;
+        if type_path.path.segments.len() == 1 {
+            *type_ = Self_.clone();
+        } else {
+            let assoc_segments =
+                type_path.path.segments.iter().skip(1)
+            ;
+            *type_ = parse_quote!(
+                <#Self_ as #PathToTrait> #(:: #assoc_segments)*
+            );
+        }
+    }
+
+    fn visit_expr_mut (
+        self: &'_ mut Self,
+        expr: &'_ mut Expr,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_expr_mut(self, expr);
+
+        // 2. Handle the `CONST_PARAM` case.
+        if let Expr::Path(ExprPath { qself: None, ref path, .. }) = *expr {
+            if let Some(arg) = self.ty_or_const_arg(path) {
+                *expr = Expr::Verbatim(quote!( { #arg } ));
+            }
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! `#[gat(auto_outlives)]` on a `trait` definition: the outlives bounds
+//! which the methods of the trait require of its GATs (_e.g._,
+//! `where Self : 'next`, for a `fn next(&mut self) -> Self::Item<'_>`) are
+//! added to them, with a warning, rather than silently missing.
+//!
+//! This mimics the "missing required bounds" check of native GATs: a method
+//! returning `Self::Assoc<'x>` out of a `&'x Self` (or `&'x T`, for a type
+//! parameter `T` of the trait) requires `Self : 'x` (or `T : 'x`), be `'x`
+//! named or elided.
+
+use super::*;
+
+pub(in super)
+fn infer (trait_: &'_ mut ItemTrait)
+  -> TokenStream2
+{
+    let gats: Vec<Ident> =
+        trait_
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                | TraitItem::Type(TraitItemType { ref ident, ref generics, .. })
+                    if generics.lifetimes().next().is_some()
+                => {
+                    Some(ident.clone())
+                },
+                | _ => None,
+            })
+            .collect()
+    ;
+    let trait_ty_params: Vec<Ident> =
+        trait_
+            .generics
+            .type_params()
+            .map(|it| it.ident.clone())
+            .collect()
+    ;
+    // `(Assoc, index of the lifetime arg, bounded type, fn name)`
+    let mut required: Vec<(Ident, usize, Type, Ident)> = vec![];
+    for item in &trait_.items {
+        let sig = match *item {
+            | TraitItem::Method(TraitItemMethod { ref sig, .. }) => sig,
+            | _ => continue,
+        };
+        let output = match sig.output {
+            | ReturnType::Type(_, ref it) => it,
+            | ReturnType::Default => continue,
+        };
+        let implied_bounds = implied_bounds(&sig.inputs, &trait_ty_params);
+        let mut gat_uses = CollectGatUses { gats: &gats, uses: vec![] };
+        visit_mut::VisitMut::visit_type_mut(
+            &mut gat_uses,
+            &mut (**output).clone(),
+        );
+        for (Assoc, lifetime_args) in gat_uses.uses {
+            for (i, lifetime_arg) in lifetime_args.iter().enumerate() {
+                for (Bounded, lifetime) in &implied_bounds {
+                    if *lifetime != *lifetime_arg {
+                        continue;
+                    }
+                    if required.iter().any(|(assoc, idx, bounded, _)| {
+                        *assoc == Assoc
+                        && *idx == i
+                        && bounded.to_token_stream().to_string()
+                            == Bounded.to_token_stream().to_string()
+                    })
+                    {
+                        continue;
+                    }
+                    required.push((
+                        Assoc.clone(),
+                        i,
+                        Bounded.clone(),
+                        sig.ident.clone(),
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut warnings = quote!();
+    for (Assoc, i, Bounded, fn_name) in required {
+        let gat = trait_.items.iter_mut().find_map(|item| match *item {
+            | TraitItem::Type(ref mut it) if it.ident == Assoc => Some(it),
+            | _ => None,
+        }).unwrap();
+        let lifetime = match gat.generics.lifetimes().nth(i) {
+            | Some(it) => it.lifetime.clone(),
+            | None => continue, // Wrong number of lifetime args: let it error.
+        };
+        let bounded_str = Bounded.to_token_stream().to_string();
+        let already_present =
+            gat .generics
+                .where_clause
+                .iter()
+                .flat_map(|it| &it.predicates)
+                .any(|predicate| matches!(
+                    *predicate,
+                    WherePredicate::Type(PredicateType {
+                        ref bounded_ty,
+                        ref bounds,
+                        ..
+                    })
+                    if bounded_ty.to_token_stream().to_string() == bounded_str
+                    && bounds.iter().any(|bound| matches!(
+                        *bound,
+                        TypeParamBound::Lifetime(ref it) if *it == lifetime
+                    ))
+                ))
+        ;
+        if already_present {
+            continue;
+        }
+        gat.generics.make_where_clause().predicates.push(parse_quote!(
+            #Bounded : #lifetime
+        ));
+        warnings.extend(compile_warning(
+            &gat.ident,
+            &format!(
+                "`#[gat(auto_outlives)]`: added `where {} : {}` to `type {}`, \
+                as required by `fn {}`",
+                bounded_str, lifetime, Assoc, fn_name,
+            ),
+        ));
+    }
+    warnings
+}
+
+/// The `Ty : 'lt` bounds implied by the `&'lt Ty` inputs, where `Ty` is
+/// either `Self` or a type parameter of the trait.
+///
+/// An elided lifetime is only kept, as `'_`, for the input which the elided
+/// lifetimes of the output refer to, as per the lifetime elision rules: a
+/// `&self` receiver (or `self: &'_ Self`), or else the only reference among
+/// the inputs, when there is no other lifetime in them.
+fn implied_bounds (
+    inputs: &'_ Punctuated<FnArg, Token![,]>,
+    trait_ty_params: &'_ [Ident],
+) -> Vec<(Type, Lifetime)>
+{
+    let elided = Lifetime::new("'_", Span::call_site());
+    let as_reference = |input: &'_ FnArg| -> Option<(bool, Option<Lifetime>, Type)> {
+        Some(match *input {
+            | FnArg::Receiver(Receiver {
+                reference: Some((_, ref lifetime)),
+                ..
+            }) => {
+                (true, lifetime.clone(), parse_quote!( Self ))
+            },
+            | FnArg::Typed(PatType { ref pat, ref ty, .. }) => match **ty {
+                | Type::Reference(TypeReference {
+                    ref lifetime,
+                    ref elem,
+                    ..
+                }) => (
+                    matches!(
+                        **pat,
+                        Pat::Ident(PatIdent { ref ident, .. }) if ident == "self"
+                    ),
+                    lifetime.clone(),
+                    (**elem).clone(),
+                ),
+                | _ => return None,
+            },
+            | FnArg::Receiver(_) => return None,
+        })
+    };
+    let elision_source =
+        inputs
+            .iter()
+            .position(|input| matches!(as_reference(input), Some((true, ..))))
+            .or_else(|| {
+                let mut count = CountLifetimes(0);
+                for input in inputs {
+                    visit_mut::visit_fn_arg_mut(&mut count, &mut input.clone());
+                }
+                if count.0 != 1 {
+                    return None;
+                }
+                inputs.iter().position(|input| as_reference(input).is_some())
+            })
+    ;
+    let mut ret = vec![];
+    for (i, input) in inputs.iter().enumerate() {
+        let (_, lifetime, ty) = match as_reference(input) {
+            | Some(it) => it,
+            | None => continue,
+        };
+        match ty {
+            | Type::Path(TypePath { qself: None, ref path })
+                if path.is_ident("Self")
+                || trait_ty_params.iter().any(|it| path.is_ident(it))
+            => {},
+            | _ => continue,
+        }
+        if let Some(lifetime) = lifetime.filter(|it| it.ident != "_") {
+            ret.push((ty.clone(), lifetime));
+        }
+        if elision_source == Some(i) {
+            ret.push((ty, elided.clone()));
+        }
+    }
+    ret
+}
+
+/// The number of lifetimes (elided or not) among the inputs of a function.
+struct CountLifetimes(usize);
+
+impl visit_mut::VisitMut for CountLifetimes {
+    fn visit_lifetime_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Lifetime,
+    )
+    {
+        self.0 += 1;
+    }
+
+    fn visit_type_reference_mut (
+        self: &'_ mut Self,
+        type_reference: &'_ mut TypeReference,
+    )
+    {
+        if type_reference.lifetime.is_none() {
+            self.0 += 1;
+        }
+        visit_mut::visit_type_reference_mut(self, type_reference); // subrecurse
+    }
+
+    fn visit_receiver_mut (
+        self: &'_ mut Self,
+        receiver: &'_ mut Receiver,
+    )
+    {
+        if matches!(receiver.reference, Some((_, None))) {
+            self.0 += 1;
+        }
+        visit_mut::visit_receiver_mut(self, receiver); // subrecurse
+    }
+}
+
+/// The lifetime args (`'_` for elided ones) of each `Self::Assoc<…>` path.
+struct CollectGatUses<'gats> {
+    gats: &'gats [Ident],
+    uses: Vec<(Ident, Vec<Lifetime>)>,
+}
+
+impl visit_mut::VisitMut for CollectGatUses<'_> {
+    fn visit_type_path_mut (
+        self: &'_ mut Self,
+        type_path: &'_ mut TypePath,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_type_path_mut(self, type_path);
+
+        // 2. Handle the `Self::Assoc<…>` case.
+        let segments = &type_path.path.segments;
+        if  type_path.qself.is_some()
+        ||  segments.len() < 2
+        ||  segments[0].ident != "Self"
+        ||  self.gats.contains(&segments[1].ident).not()
+        {
+            return;
+        }
+        let args = match segments[1].arguments {
+            | PathArguments::AngleBracketed(ref it) => &it.args,
+            | _ => return,
+        };
+        self.uses.push((
+            segments[1].ident.clone(),
+            args.iter()
+                .filter_map(|arg| match *arg {
+                    | GenericArgument::Lifetime(ref it) => Some(it.clone()),
+                    | _ => None,
+                })
+                .collect()
+            ,
+        ));
+    }
+}
@@ -0,0 +1,220 @@
+//! `#[gat(Self : Trait + OtherTrait…)]` on an item other than a `#[gat]`
+//! trait definition or implementation (_e.g._, an inherent `impl`, or the
+//! `impl` of an extension trait), so that its `Self::Assoc<…>` paths can be
+//! resolved, as in a `#[gat] impl`.
+//!
+//! The GATs of each trait are queried, in turn, through its `TraitඞGATs!`
+//! macro, so as to know which trait each `Self::Assoc<…>` path refers to.
+
+use super::*;
+
+pub(in super)
+struct SelfBounds {
+    pub(in super) bounds: Punctuated<TypeParamBound, Token![+]>,
+}
+
+impl Parse for SelfBounds {
+    fn parse (input: ParseStream<'_>)
+      -> Result<SelfBounds>
+    {
+        let _: Token![Self] = input.parse()?;
+        let _: Token![:] = input.parse()?;
+        let bounds = Punctuated::parse_separated_nonempty(input)?;
+        for bound in &bounds {
+            match *bound {
+                | TypeParamBound::Trait(TraitBound {
+                    paren_token: None,
+                    modifier: TraitBoundModifier::None,
+                    lifetimes: None,
+                    ..
+                }) => {},
+                | _ => bail! {
+                    "expected a `#[gat]` trait" => bound,
+                },
+            }
+        }
+        Ok(SelfBounds { bounds })
+    }
+}
+
+impl ToTokens for SelfBounds {
+    fn to_tokens (self: &'_ Self, out: &'_ mut TokenStream2)
+    {
+        let Self { bounds } = self;
+        out.extend(quote!( Self : #bounds ));
+    }
+}
+
+impl SelfBounds {
+    fn traits (self: &'_ Self)
+      -> impl '_ + Iterator<Item = &'_ Path>
+    {
+        self.bounds.iter().map(|bound| match *bound {
+            | TypeParamBound::Trait(ref it) => &it.path,
+            | TypeParamBound::Lifetime(_) => unreachable!(),
+        })
+    }
+}
+
+/// Fetch the metadata of the next trait (the `already_fetched`-th one),
+/// through its `TraitඞGATs!` macro, which shall then call back into
+/// `gat_self()`.
+pub(in super)
+fn defer (
+    self_bounds: SelfBounds,
+    already_fetched: Vec<TraitMetadata>,
+    item: Item,
+) -> Result<TokenStream2>
+{
+    let mut PathToTraitGATs @ _ =
+        self_bounds.traits().nth(already_fetched.len()).unwrap().clone()
+    ;
+    let trait_ = PathToTraitGATs.segments.last_mut().unwrap();
+    trait_.ident = combine_trait_name_into_gats_macro(&trait_.ident);
+    trait_.arguments = PathArguments::None;
+    Ok(quote!(
+        #PathToTraitGATs! {
+            [__gat_self]
+            { #self_bounds }
+            #({ #already_fetched })*
+            #item
+        }
+    ))
+}
+
+pub(in super)
+fn gat_self (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let (self_bounds, metadata, item) = Parser::parse2(
+        |input: ParseStream<'_>| {
+            let last_fetched = {
+                let metadata;
+                braced!(metadata in input);
+                metadata.parse::<TraitMetadata>()?
+            };
+            let self_bounds = {
+                let self_bounds;
+                braced!(self_bounds in input);
+                self_bounds.parse::<SelfBounds>()?
+            };
+            let mut metadata = vec![];
+            while input.peek(token::Brace) {
+                let it;
+                braced!(it in input);
+                metadata.push(it.parse::<TraitMetadata>()?);
+            }
+            metadata.push(last_fetched);
+            Ok((self_bounds, metadata, input.parse::<Item>()?))
+        },
+        input,
+    )?;
+    if metadata.len() < self_bounds.bounds.len() {
+        return defer(self_bounds, metadata, item);
+    }
+    handle(self_bounds, metadata, item)
+}
+
+fn handle (
+    self_bounds: SelfBounds,
+    metadata: Vec<TraitMetadata>,
+    mut item: Item,
+) -> Result<TokenStream2>
+{
+    let mut visitor = ReplaceSelfAssocLtWithSelfAsTraitsAssocLt {
+        traits:
+            self_bounds
+                .traits()
+                .zip(&metadata)
+                .map(|(path, metadata)| (
+                    path.clone(),
+                    metadata.gats().map(|it| it.ident.clone()).collect(),
+                ))
+                .collect()
+        ,
+        errors: None,
+    };
+    visit_mut::visit_item_mut(&mut visitor, &mut item);
+    if let Some(err) = visitor.errors {
+        utils::recover(err);
+    }
+    Ok(adjugate::adjugate(parse::Nothing, item).into_token_stream())
+}
+
+/// `Self::Assoc<…>` becomes `<Self as Trait>::Assoc<…>`, for the one `Trait`
+/// (among the given ones) having a GAT named `Assoc`.
+struct ReplaceSelfAssocLtWithSelfAsTraitsAssocLt {
+    traits: Vec<(Path, Vec<Ident>)>,
+    errors: Option<Error>,
+}
+
+impl visit_mut::VisitMut
+    for ReplaceSelfAssocLtWithSelfAsTraitsAssocLt
+{
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_type_path_mut (
+        self: &'_ mut Self,
+        type_path: &'_ mut TypePath,
+    )
+    {
+        // 1. subrecurse
+        visit_mut::visit_type_path_mut(self, type_path);
+
+        // 2. Handle the `Self::` case.
+        if  type_path.qself.is_some()
+        ||  type_path.path.segments.first().unwrap().ident != "Self"
+        ||  type_path.path.segments.len() < 2
+        ||  matches!(
+                type_path.path.segments[1].arguments,
+                PathArguments::AngleBracketed { .. },
+            ).not()
+        {
+            return;
+        }
+        let Assoc @ _ = &type_path.path.segments[1].ident;
+        let mut candidates =
+            self.traits
+                .iter()
+                .filter(|(_, gats)| gats.contains(Assoc))
+                .map(|(path, _)| path)
+        ;
+        let err = match (candidates.next(), candidates.next()) {
+            | (Some(Trait @ _), None) => {
+                let each_assoc_segment = type_path.path.segments.iter().skip(1);
+                *type_path = parse_quote!(
+                    <Self as #Trait> #(:: #each_assoc_segment)*
+                );
+                return;
+            },
+            | (None, _) => Error::new_spanned(
+                &type_path,
+                format_args!(
+                    "none of the `Self : …` traits has a GAT named `{}`",
+                    Assoc,
+                ),
+            ),
+            | (Some(_), Some(_)) => Error::new_spanned(
+                &type_path,
+                format_args!(
+                    "ambiguous associated type: several of the `Self : …` \
+                    traits have a GAT named `{0}`. \
+                    Qualify it, as in `<Self as Trait>::{0}<…>`",
+                    Assoc,
+                ),
+            ),
+        };
+        *type_path = utils::stub_type_path(type_path);
+        match self.errors {
+            | Some(ref mut errors) => errors.combine(err),
+            | ref mut it @ None => *it = Some(err),
+        }
+    }
+}
@@ -1,10 +1,203 @@
 use super::*;
 
+/// `#[gat(dyn, auto_outlives, crate = path::to::nougat)]` (each of them being
+/// optional).
+#[derive(Default)]
+pub(in super)
+struct Args {
+    pub(in super) dyn_: Option<Token![dyn]>,
+    pub(in super) auto_outlives: Option<Ident>,
+    pub(in super) krate: Option<Path>,
+}
+
+impl Parse for Args {
+    fn parse (input: ParseStream<'_>)
+      -> Result<Args>
+    {
+        let mut ret = Args::default();
+        while input.is_empty().not() {
+            if input.peek(Token![dyn]) && ret.dyn_.is_none() {
+                ret.dyn_ = Some(input.parse()?);
+            } else if input.peek(Token![crate]) && ret.krate.is_none() {
+                let _: Token![crate] = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                ret.krate = Some(Path::parse_mod_style(input)?);
+            } else {
+                let ident: Ident = input.parse()?;
+                if ident != "auto_outlives" || ret.auto_outlives.is_some() {
+                    bail!("expected `dyn`, `auto_outlives`, or `crate = …`" => ident);
+                }
+                ret.auto_outlives = Some(ident);
+            }
+            if input.is_empty().not() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+        Ok(ret)
+    }
+}
+
 pub(in super)
 fn handle (
     mut trait_: ItemTrait,
+    dyn_: Option<Token![dyn]>,
+    auto_outlives: Option<Ident>,
+    krate: Option<Path>,
 ) -> Result<TokenStream2>
 {
+    // The `where Self : 'lt` clauses (and the like) which the methods of the
+    // trait require of its GATs, as with native GATs.
+    let warnings = if auto_outlives.is_some() {
+        auto_outlives::infer(&mut trait_)
+    } else {
+        quote!()
+    };
+
+    // Before any kind of rewriting.
+    let mut metadata = TraitMetadata {
+        dyn_,
+        trait_: ItemTrait {
+            attrs: vec![],
+            vis: Visibility::Inherited,
+            colon_token: None,
+            supertraits: Punctuated::new(),
+            items:
+                trait_
+                    .items
+                    .iter()
+                    .filter_map(|item| match *item {
+                        | TraitItem::Type(ref it)
+                            if it.generics.params.is_empty().not()
+                        => {
+                            // The `impl`s get to see the same stub as the
+                            // trait, should the GAT not be supported (the
+                            // error being reported by the trait alone).
+                            let mut generics = it.generics.clone();
+                            if LGat::parse_generics(generics.clone()).is_err() {
+                                LGat::stub(&mut generics);
+                            }
+                            Some(TraitItem::Type(TraitItemType {
+                                generics,
+                                // Only keep the `#[gat(covariant)]` markers.
+                                attrs:
+                                    it  .attrs
+                                        .iter()
+                                        .filter(|attr| attr.path.is_ident("gat"))
+                                        .cloned()
+                                        .collect()
+                                ,
+                                ..it.clone()
+                            }))
+                        },
+                        | _ => None,
+                    })
+                    .collect()
+            ,
+            ..trait_.clone()
+        },
+    };
+
+    // `#[gat(covariant)]` GATs get a covariance witness.
+    let mut upcasts: Vec<TraitItem> = vec![];
+    for item in &mut trait_.items {
+        let gat = match *item {
+            | TraitItem::Type(ref mut it)
+                if it.generics.params.is_empty().not()
+            => it,
+            | _ => continue,
+        };
+        let mut covariant = false;
+        for attr in mem::take(&mut gat.attrs) {
+            match is_covariant_marker(&attr) {
+                | Ok(true) => covariant = true,
+                | Ok(false) => gat.attrs.push(attr),
+                // Drop the malformed marker.
+                | Err(err) => utils::recover(err),
+            }
+        }
+        if covariant {
+            let upcast_signature = upcast_signature(&gat.ident, &gat.generics);
+            let Assoc @ _ = &gat.ident;
+            let doc = format!(
+                " Covariance witness of `Self::{}<…>`: shortens its lifetime(s).",
+                Assoc,
+            );
+            upcasts.push(parse_quote!(
+                #[doc = #doc]
+                #upcast_signature;
+            ));
+        }
+    }
+    trait_.items.extend(upcasts);
+
+    let own_gats: Vec<Ident> =
+        trait_
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                | TraitItem::Type(TraitItemType {
+                    ref ident,
+                    ref generics,
+                    ..
+                })
+                    if generics.params.is_empty().not()
+                => {
+                    Some(ident.clone())
+                },
+                | _ => None,
+            })
+            .collect()
+    ;
+
+    // The GATs of the supertraits (_e.g._, `Self::Item<'_>` within a
+    // `trait DoubleEnded : LendingIterator`).
+    let inherited_gats = {
+        let mut visitor = ReplaceSelfAssocLtWithSelfAsSupertraitAssocLt {
+            own_gats: own_gats.clone(),
+            supertraits:
+                trait_
+                    .supertraits
+                    .iter()
+                    .filter_map(|bound| match *bound {
+                        | TypeParamBound::Trait(TraitBound {
+                            paren_token: None,
+                            modifier: TraitBoundModifier::None,
+                            lifetimes: None,
+                            ref path,
+                        })
+                            if is_auto_trait(path).not()
+                            && matches!(
+                                path.segments.last().unwrap().arguments,
+                                PathArguments::Parenthesized { .. },
+                            ).not()
+                        => {
+                            Some(path.clone())
+                        },
+                        | _ => None,
+                    })
+                    .collect()
+            ,
+            inherited_gats: vec![],
+            errors: None,
+        };
+        visit_mut::VisitMut::visit_item_trait_mut(&mut visitor, &mut trait_);
+        if let Some(err) = visitor.errors {
+            utils::recover(err);
+        }
+        visitor.inherited_gats
+    };
+
+    if dyn_.is_some() {
+        visit_mut::VisitMut::visit_item_trait_mut(
+            &mut ReplaceSelfAssocLtWithSelfAssocAsHkt {
+                trait_name: trait_.ident.clone(),
+                trait_args: generic_args_of(&trait_.generics),
+                dyn_gats: own_gats,
+            },
+            &mut trait_,
+        );
+    }
+
     // Conr-"adjugate" first, to tweak the impl bounds and so on.
     trait_ = {
         visit_mut::VisitMut::visit_item_trait_mut(
@@ -36,14 +229,83 @@ fn handle (
                 | TraitItem::Type(it) => LGat::from_trait_def(it),
                 | _ => unreachable!(),
             })
-            .collect::<Result<_>>()?
+            .collect()
     ;
 
-    let mut ret = quote!();
+    let mut ret = warnings;
+
+    // The helper traits of the inherited GATs are re-exported under the name
+    // of this trait, so that `<T as Trait>::Assoc<…>` paths (_e.g._, the
+    // `Self::Assoc<…>` of the `impl`s) resolve to them. This requires the
+    // supertrait to be given the same generic args as this trait, since
+    // these paths only mention the latter.
+    //
+    // Otherwise (_e.g._, `trait Peek<T> : LendingIterator`), they are
+    // re-exported as `TraitඞSuperඞAssoc` instead, and the supertrait is
+    // recorded in the metadata, for the `impl`s to rewrite their
+    // `Self::Assoc<…>` as `<Self as TraitඞSuper<…>>::Assoc<…>`, with the
+    // generic args of the supertrait.
+    for (Assoc, Supertrait) in &inherited_gats {
+        let fwd_generics = trait_.generics.split_for_impl().1;
+        let mut PathToSupertrait_Assoc = Supertrait.clone();
+        let last = PathToSupertrait_Assoc.segments.last_mut().unwrap();
+        let Trait_Assoc =
+            if last.arguments.to_token_stream().to_string()
+            == fwd_generics.to_token_stream().to_string()
+            {
+                combine_trait_name_and_assoc_type(&trait_.ident, Assoc)
+            } else {
+                if metadata.trait_.supertraits.is_empty() {
+                    metadata.trait_.colon_token = Some(<_>::default());
+                    metadata.trait_.supertraits.push(parse_quote!(
+                        #Supertrait
+                    ));
+                }
+                combine_trait_name_and_assoc_type(
+                    &combine_trait_name_into_super(&trait_.ident),
+                    Assoc,
+                )
+            }
+        ;
+        last.ident = combine_trait_name_and_assoc_type(&last.ident, Assoc);
+        last.arguments = PathArguments::None;
+        let pub_ = &trait_.vis;
+        ret.extend(quote!(
+            #[doc(hidden)] /** Not part of the public API */
+            #pub_ use #PathToSupertrait_Assoc as #Trait_Assoc;
+        ));
+    }
+
+    // The helper trait of each GAT, along with its `__ImplicitBounds`, if any,
+    // for the helper traits of its siblings to refer to it (see below).
+    let siblings: Vec<(Ident, Generics, Option<Vec<Type>>)> =
+        lgats
+            .iter()
+            .map(|lgat| {
+                let each_lgat_lifetime = &lgat.generic_lifetimes;
+                (
+                    combine_trait_name_and_assoc_type(
+                        &trait_.ident,
+                        &lgat.ident,
+                    ),
+                    parse_quote!( <#(#each_lgat_lifetime),*> ),
+                    lgat.implicit_bounds(),
+                )
+            })
+            .collect()
+    ;
 
     // Add the super traits:
     trait_.colon_token.get_or_insert_with(<Token![:]>::default);
     for lgat in lgats {
+        if dyn_.is_some() {
+            // A GAT which cannot be expressed is dropped.
+            match dyn_gat_helpers(&mut trait_, lgat) {
+                | Ok(helpers) => ret.extend(helpers),
+                | Err(err) => utils::recover(err),
+            }
+            continue;
+        }
         let TraitName @ _ = combine_trait_name_and_assoc_type(
             &trait_.ident,
             &lgat.ident,
@@ -52,43 +314,112 @@ fn handle (
         for lifetime in lgat.generic_lifetimes.iter().rev() {
             generics.params.insert(0, parse_quote!( #lifetime ));
         }
-        generics.params.push({
-            let EachImplicitBoundTy =
-                lgat.super_types.iter().map(|(lt, SuperTy)| -> Type {
-                    // we have a `where SuperTy : 'lt` bound from the
-                    // GAT definition; we thus provide an extra and
-                    // defaulted (so as to keep it hidden) type:
-                    // `&'lt SuperTy`, since be the mere fact of being
-                    // *mentioned* it introduces an **implicit** such
-                    // bound.
-                    //
-                    // The bound has to be implicit (vs. the more
-                    // straight-forward `where SuperTy : 'lt` approach)
-                    // so that the `for<'lt> SuperTrait<'lt>`
-                    // quantification correctly holds (the implicit
-                    // bound will correctly bound the `for<'lt>` rather
-                    // than appear as an unmet requirement outside of a
-                    // too-general `for` quantification).
-                    parse_quote!(
-                        & #lt #SuperTy
-                    )
-                })
+        // `<'gat…, 'trait…, GatTyOrConst…, TraitTyOrConst…>`
+        let first_pos = count_lifetime_params(&generics.params);
+        for (i, param) in lgat.generic_tys_and_consts.iter().enumerate() {
+            generics.params.insert(first_pos + i, param.clone());
+        }
+        let is_quantifiable = lgat.generic_tys_and_consts.is_empty();
+        if is_quantifiable.not() {
+            // No `for<…>` quantification involved: a classic `where` clause
+            // does the job.
+            for (lt, SuperTy) in &lgat.super_types {
+                generics.make_where_clause().predicates.push(parse_quote!(
+                    #SuperTy : #lt
+                ));
+            }
+            if lgat.where_predicates.is_empty().not() {
+                generics.make_where_clause().predicates.extend(
+                    lgat.where_predicates.iter().cloned()
+                );
+            }
+        }
+        // Otherwise, the `where_predicates` can't be put on the helper trait,
+        // since it is `for<…>`-quantified as a supertrait: it would require
+        // them of every `Trait` bound (and of the `trait` definition itself).
+        // They are thus only put on the helper impls (see `trait_impl`).
+
+        // The generics with which to refer to the helper trait (the extra
+        // defaulted params below are not meant to be provided).
+        let fwd_generics = generics.clone();
+        // Since the helper trait is not a subtrait of `Trait` (rather the other
+        // way around), the associated items of `Self` (_e.g._, `Self::Elem`)
+        // are not available to its bounds: they are provided as extra
+        // (defaulted) params instead.
+        let mut bounds = lgat.bounds.clone();
+        let (hoisted, sibling_helpers) = hoist_self_projections(
+            &trait_,
+            &generics,
+            siblings.iter().map(|(it, _, implicit_bounds)| {
+                (it, implicit_bounds.is_some())
+            }),
+            &mut bounds,
+        );
+        for (SelfAssoc_, SelfAssoc, maybe_unsized) in hoisted {
+            let maybe_unsized = if maybe_unsized { quote!(?Sized) } else { quote!() };
+            generics.params.push(parse_quote!(
+                #SelfAssoc_ : #maybe_unsized = #SelfAssoc
+            ));
+        }
+        // The sibling GATs (_e.g._, `Self::Ref<'a>` in the bounds of a
+        // `Mut<'a>` GAT) are, on the other hand, required of `Self` by the
+        // helper trait itself (it is fine for such a `where` clause to be
+        // `for<…>`-quantified, since `Trait` has that very supertrait).
+        //
+        // Their `__ImplicitBounds` param is to be mentioned (through yet
+        // another defaulted param), lest `Self : 'a` be required by the
+        // `where` clause.
+        for Trait_Sibling in sibling_helpers {
+            let last = Trait_Sibling.segments.last().unwrap();
+            let (_, lifetime_generics, implicit_bounds) =
+                siblings.iter().find(|(it, _, _)| *it == last.ident).unwrap()
             ;
-            parse_quote!(
+            if let Some(implicit_bounds) = implicit_bounds {
+                let args = match last.arguments {
+                    | PathArguments::AngleBracketed(ref it) => &it.args,
+                    | _ => unreachable!(),
+                };
+                let ref mut substitute = SubstituteTraitGenerics::new(
+                    &{
+                        let Trait @ _ = &trait_.ident;
+                        let fwd_generics = trait_.generics.split_for_impl().1;
+                        parse_quote!( #Trait #fwd_generics )
+                    },
+                    &trait_.generics,
+                    parse_quote!( Self ),
+                )?;
+                substitute.add_generics(lifetime_generics, args);
+                let SiblingBounds_ = args.last().unwrap();
+                let EachImplicitBoundTy = implicit_bounds.iter().map(|ty| {
+                    let mut ty = ty.clone();
+                    visit_mut::VisitMut::visit_type_mut(substitute, &mut ty);
+                    ty
+                });
+                generics.params.push(parse_quote!(
+                    #SiblingBounds_ = (#(#EachImplicitBoundTy,)*)
+                ));
+            }
+            generics.make_where_clause().predicates.push(parse_quote!(
+                Self : #Trait_Sibling
+            ));
+        }
+        if let Some(EachImplicitBoundTy) = lgat.implicit_bounds() {
+            generics.params.push(parse_quote!(
                 __ImplicitBounds = (#(
                     #EachImplicitBoundTy,
                 )*)
-            )
-        });
-        let bounds = &lgat.bounds;
+            ));
+        }
         let (intro_generics, where_clause) = (
             &generics.params,
             &generics.where_clause,
         );
         // let LGat { attrs, .. } = &lgat;
         let pub_ = &trait_.vis;
+        let on_unimplemented = on_unimplemented(&trait_.ident, &lgat);
         ret.extend(quote!(
             // #(#attrs)*
+            #on_unimplemented
             #[allow(warnings, clippy::all)]
             #pub_
             trait #TraitName <#intro_generics>
@@ -98,40 +429,508 @@ fn handle (
             }
         ));
         //
-        let fwd_generics = {
-            // Do not include the implicit bound parameter
-            drop(generics.params.pop());
-            generics.split_for_impl().1
-        };
+        let fwd_generics = fwd_generics.split_for_impl().1;
+        if is_quantifiable.not() {
+            // No `for<T>` quantification in Rust: the
+            // `Self : TraitඞAssoc<…>` bounds will, instead, be added to the
+            // signatures mentioning such types (see `adjugate`).
+            continue;
+        }
         let each_lgat_lifetime = &lgat.generic_lifetimes;
         trait_.supertraits.push(parse_quote!(
             for<#(#each_lgat_lifetime),*> #TraitName #fwd_generics
         ));
     }
 
+    ret.extend(gats_macro(&trait_, metadata, krate));
+
     trait_.to_tokens(&mut ret);
 
     Ok(ret)
 }
 
+/// `Self::Assoc…` (or `<Self as …>::Assoc…`) becomes `__SelfAssoc0`, and
+/// so on: returns each such param along with the (qualified) projection it
+/// stands for, and whether it may be unsized (_i.e._, whether it is an
+/// associated type of `Trait` with a `?Sized` bound).
+///
+/// The projections onto the helper traits of sibling GATs (_e.g._,
+/// `<Self as TraitඞSibling<'a>>::T`) are kept, and the paths to these helper
+/// traits are returned instead, to be required of `Self`. When the sibling
+/// has `__ImplicitBounds`, they are provided, as `__SiblingBounds0` and so
+/// on, since the default would otherwise be checked.
+///
+/// Projections involving lifetimes other than the params of the helper trait
+/// (_e.g._, `for<…>`-quantified ones) are left untouched.
+fn hoist_self_projections<'sibling> (
+    trait_: &'_ ItemTrait,
+    helper_generics: &'_ Generics,
+    sibling_helpers: impl Iterator<Item = (
+        &'sibling Ident,
+        /* has_implicit_bounds: */ bool,
+    )>,
+    bounds: &'_ mut Punctuated<TypeParamBound, Token![+]>,
+) -> (Vec<(Ident, Type, bool)>, Vec<Path>)
+{
+    let TraitName @ _ = &trait_.ident;
+    let fwd_generics = trait_.generics.split_for_impl().1;
+    let mut visitor = HoistSelfProjections {
+        Trait: parse_quote!( #TraitName #fwd_generics ),
+        lifetimes:
+            helper_generics
+                .lifetimes()
+                .map(|it| it.lifetime.clone())
+                .collect()
+        ,
+        unsized_assoc_types:
+            trait_
+                .items
+                .iter()
+                .filter_map(|item| match *item {
+                    | TraitItem::Type(TraitItemType {
+                        ref ident,
+                        ref bounds,
+                        ..
+                    })
+                        if bounds.iter().any(|bound| matches!(
+                            *bound,
+                            TypeParamBound::Trait(TraitBound {
+                                modifier: TraitBoundModifier::Maybe(_),
+                                ref path,
+                                ..
+                            })
+                            if path.segments.last().unwrap().ident == "Sized"
+                        ))
+                    => {
+                        Some(ident.clone())
+                    },
+                    | _ => None,
+                })
+                .collect()
+        ,
+        sibling_helpers:
+            sibling_helpers
+                .map(|(it, has_implicit_bounds)| (it.clone(), has_implicit_bounds))
+                .collect()
+        ,
+        hoisted: vec![],
+        siblings: vec![],
+    };
+    bounds.iter_mut().for_each(|bound| {
+        visit_mut::VisitMut::visit_type_param_bound_mut(&mut visitor, bound);
+    });
+    (visitor.hoisted, visitor.siblings)
+}
+
+struct HoistSelfProjections {
+    Trait: Path,
+    lifetimes: Vec<Lifetime>,
+    unsized_assoc_types: Vec<Ident>,
+    sibling_helpers: Vec<(Ident, bool)>,
+    hoisted: Vec<(Ident, Type, bool)>,
+    siblings: Vec<Path>,
+}
+
+impl visit_mut::VisitMut for HoistSelfProjections {
+    fn visit_item_mut (
+        self: &'_ mut Self,
+        _: &'_ mut Item,
+    )
+    {
+        /* do not subrecurse */
+    }
+
+    fn visit_type_mut (
+        self: &'_ mut Self,
+        ty: &'_ mut Type,
+    )
+    {
+        let mut projection = match *ty {
+            | Type::Path(TypePath { qself: Some(ref qself), .. })
+                if matches!(
+                    *qself.ty,
+                    Type::Path(TypePath { qself: None, ref path })
+                    if path.is_ident("Self")
+                )
+            => {
+                ty.clone()
+            },
+            // `Self::Assoc…` becomes `<Self as Trait>::Assoc…`.
+            | Type::Path(TypePath { qself: None, ref path })
+                if path.segments.len() >= 2
+                && path.segments[0].ident == "Self"
+            => {
+                let Trait @ _ = &self.Trait;
+                let each_assoc_segment = path.segments.iter().skip(1);
+                parse_quote!(
+                    <Self as #Trait> #(:: #each_assoc_segment)*
+                )
+            },
+            // The GAT paths already rewritten by `adjugate` (_e.g._,
+            // `<Self as TraitඞSibling<'a>>::T`, for a `Self::Sibling<'a>`).
+            | Type::Verbatim(ref tts) => match parse2::<Type>(tts.clone()) {
+                | Ok(it) if matches!(it, Type::Verbatim(_)).not() => {
+                    *ty = it;
+                    return self.visit_type_mut(ty);
+                },
+                | _ => return,
+            },
+            | _ => return visit_mut::visit_type_mut(self, ty), // subrecurse
+        };
+        // Make sure the projection only involves params of the helper trait.
+        struct Lifetimes<'lts>(&'lts [Lifetime], bool);
+        impl visit_mut::VisitMut for Lifetimes<'_> {
+            fn visit_lifetime_mut (
+                self: &'_ mut Self,
+                lifetime: &'_ mut Lifetime,
+            )
+            {
+                self.1 &=
+                    lifetime.ident == "static" || self.0.contains(lifetime)
+                ;
+            }
+        }
+        let mut lifetimes = Lifetimes(&self.lifetimes, true);
+        visit_mut::VisitMut::visit_type_mut(&mut lifetimes, &mut projection);
+        if lifetimes.1.not() {
+            return;
+        }
+        if let Type::Path(TypePath { qself: Some(ref qself), ref mut path }) =
+            projection
+        {
+            let sibling =
+                self.sibling_helpers
+                    .iter()
+                    .find(|(it, _)| *it == path.segments[qself.position - 1].ident)
+            ;
+            if let Some(&(_, has_implicit_bounds)) = sibling {
+                if has_implicit_bounds {
+                    let SiblingBounds_ = format_ident!(
+                        "__SiblingBounds{}", self.siblings.len(),
+                    );
+                    match path.segments[qself.position - 1].arguments {
+                        | PathArguments::AngleBracketed(ref mut it) => {
+                            it.args.push(parse_quote!( #SiblingBounds_ ));
+                        },
+                        | _ => unreachable!(),
+                    }
+                }
+                let Trait_Sibling = Path {
+                    leading_colon: path.leading_colon,
+                    segments:
+                        path.segments.iter().take(qself.position).cloned().collect()
+                    ,
+                };
+                self.siblings.push(Trait_Sibling);
+                *ty = projection;
+                return;
+            }
+        }
+        let key = projection.to_token_stream().to_string();
+        let SelfAssoc_ = match
+            self.hoisted
+                .iter()
+                .find(|(_, it, _)| it.to_token_stream().to_string() == key)
+        {
+            | Some((it, _, _)) => it.clone(),
+            | None => {
+                let it = format_ident!("__SelfAssoc{}", self.hoisted.len());
+                // `<Self as Trait>::Assoc`, with `type Assoc : ?Sized`.
+                let maybe_unsized = match projection {
+                    | Type::Path(TypePath { ref path, .. }) => {
+                        let Trait @ _ = &self.Trait;
+                        let Assoc @ _ = &path.segments.last().unwrap().ident;
+                        let Trait_Assoc: Path = parse_quote!( #Trait :: #Assoc );
+                        self.unsized_assoc_types.contains(Assoc)
+                        &&  path.to_token_stream().to_string()
+                            ==
+                            Trait_Assoc.to_token_stream().to_string()
+                    },
+                    | _ => false,
+                };
+                self.hoisted.push((it.clone(), projection, maybe_unsized));
+                it
+            },
+        };
+        *ty = parse_quote!( #SelfAssoc_ );
+    }
+}
+
+/// `TraitඞGATs! { [__callback] input… }` expands to
+/// `::nougat::ඞ::__callback! { { <TraitMetadata> } input… }`.
+///
+/// The `#[macro_export]`ed macro itself gets an unnameable name, and is then
+/// `use`d under the `TraitඞGATs` name, so as to be namespaced and
+/// privacy-scoped just like the `trait` it describes (and the helper traits).
+///
+/// Such name has to be unique at the root of the crate, while only depending
+/// on the trait definition itself (for the build to be reproducible). Hence
+/// the hash of the trait tokens and of the span of its name: the latter is
+/// the location of that name in the crate sources (along with its macro
+/// expansion context), which no two `trait` definitions can share.
+///
+/// The path to `::nougat` is the one given by `#[gat(crate = …)]`, if any,
+/// with a leading `crate` becoming `$crate`, so that it can be resolved from
+/// the downstream crates as well (_e.g._,
+/// `#[gat(crate = crate::reexports::nougat)]`).
+fn gats_macro (
+    trait_: &'_ ItemTrait,
+    metadata: TraitMetadata,
+    krate: Option<Path>,
+) -> TokenStream2
+{
+    let krate = match krate {
+        | None => quote!( ::nougat ),
+        | Some(Path { leading_colon: None, ref segments })
+            if segments[0].ident == "crate"
+        => {
+            let each_segment = segments.iter().skip(1);
+            quote!( $crate #(:: #each_segment)* )
+        },
+        | Some(path) => path.into_token_stream(),
+    };
+    let TraitName @ _ = &trait_.ident;
+    let unique = {
+        use ::std::hash::*;
+        let ref mut hasher =
+            ::std::collections::hash_map::DefaultHasher::new()
+        ;
+        trait_.to_token_stream().to_string().hash(hasher);
+        // `#ctxt bytes(lo..hi)`
+        format!("{:?}", TraitName.span()).hash(hasher);
+        hasher.finish()
+    };
+    let macro_name = format_ident!(
+        "__nougat_{}_{:016x}", TraitName, unique,
+    );
+    let Trait_GATs = combine_trait_name_into_gats_macro(TraitName);
+    let pub_ = &trait_.vis;
+    quote!(
+        #[doc(hidden)] /** Not part of the public API */
+        #[allow(warnings)]
+        #[macro_export]
+        macro_rules! #macro_name {(
+            [$callback:ident] $($input:tt)*
+        ) => (
+            #krate::ඞ::$callback! {
+                { #metadata }
+                $($input)*
+            }
+        )}
+
+        #[doc(hidden)] /** Not part of the public API */
+        #[allow(unused_imports)]
+        #pub_ use #macro_name as #Trait_GATs;
+    )
+}
+
+/// `#[diagnostic::on_unimplemented]`, for `rustc` to talk about
+/// `Trait::Assoc<'lt, T>`, rather than about the `TraitඞAssoc<'lt, …, T, …>`
+/// helper trait, when some `X : TraitඞAssoc<…>` bound is not met (_e.g._,
+/// ``"`X` does not implement the GAT `Collection::Of<u8>`"``). The `T = …`
+/// binding mismatches (`E0271`) are out of its reach.
+///
+/// Requires Rust 1.78, hence the (opt-in) `diagnostics` feature.
+fn on_unimplemented (
+    TraitName: &'_ Ident,
+    lgat: &'_ LGat,
+) -> TokenStream2
+{
+    if cfg!(feature = "diagnostics").not() {
+        return quote!();
+    }
+    let params =
+        lgat.generic_lifetimes
+            .iter()
+            .map(|lt| lt.to_string())
+            .chain(lgat.generic_tys_and_consts.iter().map(|param| match *param {
+                // The type params of the helper trait can be interpolated.
+                | GenericParam::Type(TypeParam { ref ident, .. }) => {
+                    format!("{{{}}}", ident)
+                },
+                | GenericParam::Const(ConstParam { ref ident, .. }) => {
+                    ident.to_string()
+                },
+                | GenericParam::Lifetime(_) => unreachable!(),
+            }))
+            .collect::<Vec<_>>()
+    ;
+    let gat = format!("{}::{}<{}>", TraitName, lgat.ident, params.join(", "));
+    let message = format!("`{{Self}}` does not implement the GAT `{}`", gat);
+    let label = format!("`{}` is not available for `{{Self}}`", gat);
+    let note = format!(
+        "the GATs of `{0}` are provided by its `#[gat] impl`s, \
+        as long as the `where` clauses of the GAT are met",
+        TraitName,
+    );
+    quote!(
+        #[diagnostic::on_unimplemented(
+            message = #message,
+            label = #label,
+            note = #note,
+        )]
+    )
+}
+
+/// The `#[gat(dyn)]` flavor: `type Assoc<'lt>` becomes
+/// `type Assoc : ?Sized + for<'lt> TraitඞAssocඞHKT<'lt>`, which, contrary to
+/// supertraits, can be specified in a trait object.
+///
+/// `TraitඞAssoc` is then blanket-implemented in terms of it, so that the
+/// paths and bounds emitted by `Gat!` are the same for both flavors.
+fn dyn_gat_helpers (
+    trait_: &'_ mut ItemTrait,
+    lgat: LGat,
+) -> Result<TokenStream2>
+{
+    if let Some(param) = lgat.generic_tys_and_consts.first() {
+        bail! {
+            "non-lifetime GATs are not supported by `#[gat(dyn)]`" => param,
+        }
+    }
+    let on_unimplemented = on_unimplemented(&trait_.ident, &lgat);
+    let LGat { attrs, ident: Assoc @ _, bounds, generic_lifetimes, .. } = lgat;
+    let TraitName @ _ = &trait_.ident;
+    let Trait_Assoc = combine_trait_name_and_assoc_type(TraitName, &Assoc);
+    let Trait_Assoc_HKT =
+        combine_trait_name_and_assoc_type_into_hkt(TraitName, &Assoc)
+    ;
+    let pub_ = &trait_.vis;
+    let trait_args = generic_args_of(&trait_.generics);
+    let where_clause = &trait_.generics.where_clause;
+    let mut args: Punctuated<_, Token![,]> =
+        generic_lifetimes
+            .iter()
+            .cloned()
+            .map(GenericArgument::Lifetime)
+            .collect()
+    ;
+    Gat::merge_generic_args(&mut args, trait_args.clone());
+    let mut generics = trait_.generics.clone();
+    for lifetime in generic_lifetimes.iter().rev() {
+        generics.params.insert(0, parse_quote!( #lifetime ));
+    }
+    let intro_generics = generics.params.clone();
+    // `impl` generics cannot have defaults.
+    generics.params.iter_mut().for_each(|param| match *param {
+        | GenericParam::Type(ref mut it) => {
+            it.eq_token = None;
+            it.default = None;
+        },
+        | GenericParam::Const(ref mut it) => {
+            it.eq_token = None;
+            it.default = None;
+        },
+        | GenericParam::Lifetime(_) => {},
+    });
+    // `__Self` goes before the `const` params (required before 1.59).
+    generics.params.insert(
+        count_lifetime_params(&generics.params),
+        parse_quote!( __Self : ?Sized + #TraitName<#trait_args> ),
+    );
+    let each_impl_generic = generics.params.iter();
+    let ret = quote!(
+        #[allow(warnings, clippy::all)]
+        #pub_
+        trait #Trait_Assoc_HKT <#intro_generics>
+        #where_clause
+        {
+            type T : #bounds;
+        }
+
+        #on_unimplemented
+        #[allow(warnings, clippy::all)]
+        #pub_
+        trait #Trait_Assoc <#intro_generics>
+        #where_clause
+        {
+            type T : #bounds;
+        }
+
+        #[allow(warnings, clippy::all)]
+        impl<#(#each_impl_generic),*>
+            #Trait_Assoc<#args>
+        for
+            __Self
+        #where_clause
+        {
+            type T =
+                <
+                    <__Self as #TraitName<#trait_args>>::#Assoc
+                    as
+                    #Trait_Assoc_HKT<#args>
+                >::T
+            ;
+        }
+    );
+    trait_.items.push(parse_quote!(
+        #(#attrs)*
+        type #Assoc
+        :
+            ?Sized +
+            for<#(#generic_lifetimes),*> #Trait_Assoc_HKT<#args>
+        ;
+    ));
+    Ok(ret)
+}
+
 impl LGat {
     fn from_trait_def (assoc_ty: TraitItemType)
-      -> Result<LGat>
+      -> LGat
     {
         let TraitItemType { attrs, ident, bounds, generics, .. } = assoc_ty;
-        let (generic_lifetimes, super_types) = Self::parse_generics(generics)?;
-        if let Some((eq, _)) = assoc_ty.default {
-            bail! {
-                "default GATs are not supported" => eq,
-            }
-        }
-        Ok(LGat {
+        let (
+            generic_lifetimes,
+            generic_tys_and_consts,
+            super_types,
+            where_predicates,
+        ) = Self::parse_generics_or_stub(generics);
+        LGat {
             attrs,
             ident,
             bounds,
             generic_lifetimes,
+            generic_tys_and_consts,
             super_types,
-            value: None,
-        })
+            where_predicates,
+            // The default, if any, is only used by the `impl`s (through the
+            // `TraitඞGATs!` metadata).
+            value: assoc_ty.default.map(|(_eq, ty)| ty),
+        }
+    }
+
+    /// The types of the `__ImplicitBounds` param of the helper trait, if any.
+    ///
+    /// It is only added when needed, since a (defaulted) type parameter after
+    /// `const` ones requires Rust 1.59.
+    fn implicit_bounds (self: &'_ LGat)
+      -> Option<Vec<Type>>
+    {
+        let is_quantifiable = self.generic_tys_and_consts.is_empty();
+        if is_quantifiable.not() || self.super_types.is_empty() {
+            return None;
+        }
+        Some(
+            self.super_types.iter().map(|(lt, SuperTy)| -> Type {
+                // we have a `where SuperTy : 'lt` bound from the
+                // GAT definition; we thus provide an extra and
+                // defaulted (so as to keep it hidden) type:
+                // `&'lt SuperTy`, since be the mere fact of being
+                // *mentioned* it introduces an **implicit** such
+                // bound.
+                //
+                // The bound has to be implicit (vs. the more
+                // straight-forward `where SuperTy : 'lt` approach)
+                // so that the `for<'lt> SuperTrait<'lt>`
+                // quantification correctly holds (the implicit
+                // bound will correctly bound the `for<'lt>` rather
+                // than appear as an unmet requirement outside of a
+                // too-general `for` quantification).
+                parse_quote!(
+                    & #lt #SuperTy
+                )
+            })
+            .collect()
+        )
     }
 }
@@ -1,11 +1,30 @@
 use super::*;
 
+/// Fetch the metadata of the implemented trait, through its `TraitඞGATs!`
+/// macro, which shall then call back into `handle()` (see `gat_impl()`).
 pub(in super)
-fn handle (
-    mut impl_: ItemImpl,
+fn defer (
+    impl_: ItemImpl,
+    attrs: TokenStream2,
 ) -> Result<TokenStream2>
 {
-    let PathToTrait @ _ = match impl_.trait_ {
+    let mut PathToTraitGATs @ _ = path_to_trait(&impl_)?;
+    let trait_ = PathToTraitGATs.segments.last_mut().unwrap();
+    trait_.ident = combine_trait_name_into_gats_macro(&trait_.ident);
+    trait_.arguments = PathArguments::None;
+    Ok(quote!(
+        #PathToTraitGATs! {
+            [__gat_impl]
+            { #attrs }
+            #impl_
+        }
+    ))
+}
+
+fn path_to_trait (impl_: &'_ ItemImpl)
+  -> Result<Path>
+{
+    Ok(match impl_.trait_ {
         | Some((None, ref path, ref _for)) => path.clone(),
         | Some((Some(negative_impl), ..)) => bail! {
             "not supported" => negative_impl,
@@ -13,7 +32,58 @@ fn handle (
         | None => bail! {
             "expected `TraitName for`" => impl_.self_ty,
         },
-    };
+    })
+}
+
+pub(in super)
+fn handle (
+    mut impl_: ItemImpl,
+    dyn_: Option<Token![dyn]>,
+    metadata: TraitMetadata,
+) -> Result<TokenStream2>
+{
+    let PathToTrait @ _ = path_to_trait(&impl_)?;
+    let dyn_ = dyn_.or(metadata.dyn_);
+
+    // Provide the defaulted GATs which the `impl` does not override.
+    add_omitted_defaults(&mut impl_, &PathToTrait, &metadata)?;
+
+    // Every GAT of the trait, with the right arity (stubbed otherwise).
+    if let Err(err) = check_gats(&mut impl_, &PathToTrait, &metadata) {
+        utils::recover(err);
+    }
+
+    // Provide the covariance witnesses of the `#[gat(covariant)]` GATs.
+    add_upcasts(&mut impl_, &metadata)?;
+
+    // The GATs inherited from a supertrait given other generic args than the
+    // trait's own (see `trait_def`).
+    if let Some(TypeParamBound::Trait(TraitBound { path: Supertrait, .. })) =
+        metadata.trait_.supertraits.first()
+    {
+        let mut Supertrait @ _ = Supertrait.clone();
+        visit_mut::VisitMut::visit_path_mut(
+            &mut SubstituteTraitGenerics::new(
+                &PathToTrait,
+                &metadata.trait_.generics,
+                parse_quote!( Self ),
+            )?,
+            &mut Supertrait,
+        );
+        let mut PathToTraitSuper @ _ = PathToTrait.clone();
+        let last = PathToTraitSuper.segments.last_mut().unwrap();
+        last.ident = combine_trait_name_into_super(&last.ident);
+        last.arguments = Supertrait.segments.last().unwrap().arguments.clone();
+        visit_mut::VisitMut::visit_item_impl_mut(
+            &mut ReplaceSelfAssocLtWithSelfAsSupertraitAssocLt {
+                own_gats: metadata.gats().map(|it| it.ident.clone()).collect(),
+                supertraits: vec![PathToTraitSuper],
+                inherited_gats: vec![],
+                errors: None,
+            },
+            &mut impl_,
+        );
+    }
 
     // Conr-"adjugate" first, to tweak the impl bounds and so on.
     impl_ = {
@@ -42,18 +112,50 @@ fn handle (
                 | ImplItem::Type(it) => LGat::from_trait_impl(it),
                 | _ => unreachable!(),
             })
-            .collect::<Result<_>>()?
+            .collect()
     ;
     let mut ret = quote!();
 
     // Implement the super traits:
-    for lgat in lgats {
+    for mut lgat in lgats {
+        if let Some(trait_gat) = metadata.gats().find(|it| it.ident == lgat.ident) {
+            let (super_types, where_predicates) =
+                trait_gat_predicates(&PathToTrait, &metadata, trait_gat, &lgat)?
+            ;
+            match check_not_stricter(&impl_, &lgat, &super_types, &where_predicates) {
+                | Ok(warnings) => ret.extend(warnings),
+                | Err(err) => utils::recover(err),
+            }
+            // Not `for<…>`-quantified: the `where` clauses of the helper trait
+            // are to be repeated, even when the `impl` omits them.
+            if lgat.generic_tys_and_consts.is_empty().not() {
+                lgat.super_types = super_types;
+                lgat.where_predicates = where_predicates;
+            } else {
+                check_impl_wide_predicates(&impl_, &mut lgat);
+            }
+        }
         let mut PathToTrait @ _ = PathToTrait.clone();
         let trait_ = PathToTrait.segments.last_mut().unwrap();
-        trait_.ident = combine_trait_name_and_assoc_type(
-            &trait_.ident,
-            &lgat.ident,
-        );
+        trait_.ident = if dyn_.is_some() {
+            if let Some(param) = lgat.generic_tys_and_consts.first() {
+                // Dropped (as in the trait definition).
+                utils::recover(Error::new_spanned(
+                    param,
+                    "non-lifetime GATs are not supported by `#[gat(dyn)]`",
+                ));
+                continue;
+            }
+            combine_trait_name_and_assoc_type_into_hkt(
+                &trait_.ident,
+                &lgat.ident,
+            )
+        } else {
+            combine_trait_name_and_assoc_type(
+                &trait_.ident,
+                &lgat.ident,
+            )
+        };
         if matches!(trait_.arguments, PathArguments::None) {
             trait_.arguments = PathArguments::AngleBracketed(
                 AngleBracketedGenericArguments {
@@ -76,10 +178,64 @@ fn handle (
             trait_generic_params.insert(0, parse_quote!( #lifetime ));
             generics.params.insert(0, parse_quote!( #lifetime ));
         }
+        // `<'gat…, 'trait…, GatTyOrConst…, TraitTyOrConst…>`
+        let first_arg_pos = count_lifetime_args(trait_generic_params);
+        for (i, param) in lgat.generic_tys_and_consts.iter().enumerate() {
+            let ident = match *param {
+                | GenericParam::Type(TypeParam { ref ident, .. }) => ident,
+                | GenericParam::Const(ConstParam { ref ident, .. }) => ident,
+                | GenericParam::Lifetime(_) => unreachable!(),
+            };
+            trait_generic_params.insert(first_arg_pos + i, parse_quote!( #ident ));
+            // The `impl` generics, on the other hand, are not positional: keep
+            // the type params before the `const` ones (required before 1.59).
+            let param_pos = match *param {
+                | GenericParam::Type(_) => {
+                    generics
+                        .params
+                        .iter()
+                        .position(|it| matches!(it, GenericParam::Const(_)))
+                        .unwrap_or(generics.params.len())
+                },
+                | _ => generics.params.len(),
+            };
+            generics.params.insert(param_pos, param.clone());
+        }
+        if lgat.generic_tys_and_consts.is_empty().not() {
+            // Mirror the `where` clauses of the (non-`for`-quantified) trait.
+            for (lt, SuperTy) in &lgat.super_types {
+                generics.make_where_clause().predicates.push(parse_quote!(
+                    #SuperTy : #lt
+                ));
+            }
+        }
+        // The non-implicit predicates (_e.g._, trait bounds) are always carried
+        // over (when `for<…>`-quantified, this means the `impl`s of the trait
+        // need to meet them for every GAT lifetime).
+        if lgat.where_predicates.is_empty().not() {
+            generics.make_where_clause().predicates.extend(
+                lgat.where_predicates.iter().cloned()
+            );
+        }
         let (intro_generics, where_clause) = (
             &generics.params,
             &generics.where_clause,
         );
+        if dyn_.is_some() {
+            // `type Assoc = dyn for<'lt> TraitඞAssocඞHKT<'lt, T = …>;`
+            let LGat { attrs, ident: Assoc @ _, value, generic_lifetimes, .. } =
+                lgat
+            ;
+            trait_generic_params.push(parse_quote!( T = #value ));
+            let each_lgat_lifetime = &generic_lifetimes;
+            impl_.items.push(parse_quote!(
+                #(#attrs)*
+                type #Assoc =
+                    dyn for<#(#each_lgat_lifetime),*> #PathToTrait
+                ;
+            ));
+            continue;
+        }
         let Implementor @ _ = &impl_.self_ty;
         let AssocTyValue @ _ = &lgat.value;
         let LGat { attrs, .. } = &lgat;
@@ -102,29 +258,561 @@ fn handle (
     Ok(ret)
 }
 
+/// The `where` clauses of the GAT in the trait definition, in terms of the
+/// generic params of the `impl` (and of its GAT).
+fn trait_gat_predicates (
+    PathToTrait: &'_ Path,
+    metadata: &'_ TraitMetadata,
+    trait_gat: &'_ TraitItemType,
+    lgat: &'_ LGat,
+) -> Result<(SuperTypes, Vec<WherePredicate>)>
+{
+    let ref mut substitute = SubstituteTraitGenerics::new(
+        PathToTrait,
+        &metadata.trait_.generics,
+        parse_quote!( Self ),
+    )?;
+    let gat_args: Punctuated<GenericArgument, Token![,]> =
+        lgat.generic_lifetimes
+            .iter()
+            .map(|lt| -> GenericArgument { parse_quote!( #lt ) })
+            .chain(lgat.generic_tys_and_consts.iter().map(|param| -> GenericArgument { match *param {
+                | GenericParam::Type(TypeParam { ref ident, .. })
+                | GenericParam::Const(ConstParam { ref ident, .. })
+                => parse_quote!( #ident ),
+                | GenericParam::Lifetime(_) => unreachable!(),
+            }}))
+            .collect()
+    ;
+    substitute.add_generics(&trait_gat.generics, &gat_args);
+    let mut generics = trait_gat.generics.clone();
+    visit_mut::VisitMut::visit_generics_mut(substitute, &mut generics);
+    let (_, _, super_types, where_predicates) = LGat::parse_generics(generics)?;
+    Ok((super_types, where_predicates))
+}
+
+/// As with native GATs, the `where` clauses of a GAT in an `impl` may be
+/// omitted (or weakened), but not be stricter than those of the trait.
+///
+/// The bounds are compared structurally (once normalized, see `Normalize`),
+/// so a bound which the trait (or the `impl` itself) does not require as such,
+/// but which bounds an already bounded type, may still be implied (_e.g._,
+/// `T : Clone` by `T : Copy`): it is only warned about.
+fn check_not_stricter (
+    impl_: &'_ ItemImpl,
+    lgat: &'_ LGat,
+    trait_super_types: &'_ [(Lifetime, Option<Type>)],
+    trait_where_predicates: &'_ [WherePredicate],
+) -> Result<TokenStream2>
+{
+    let Implementor @ _ = &*impl_.self_ty;
+    let mut known_bounds =
+        each_bound(Implementor, trait_super_types, trait_where_predicates)
+    ;
+    known_bounds.extend(each_bound(Implementor, &[], &impl_predicates(impl_)));
+    let mut warnings = quote!();
+    let mut errors: Option<Error> = None;
+    for (normalized, predicate) in
+        each_bound(Implementor, &lgat.super_types, &lgat.where_predicates)
+    {
+        if known_bounds.iter().any(|(it, _)| *it == normalized) {
+            continue;
+        }
+        let Bounded @ _ = bounded(&normalized);
+        if known_bounds.iter().any(|(it, _)| bounded(it) == Bounded) {
+            warnings.extend(compile_warning(
+                &predicate,
+                &format!(
+                    "`{}` is not required as such by the trait's `type {}`: \
+                    unless implied by its other bounds on `{}`, the `impl` \
+                    is stricter than the trait",
+                    predicate, lgat.ident, Bounded.into_token_stream(),
+                ),
+            ));
+            continue;
+        }
+        let err = Error::new_spanned(
+            &predicate,
+            format_args!(
+                "impl has stricter requirements than trait: \
+                `{}` is not required by the trait's `type {}`",
+                predicate, lgat.ident,
+            ),
+        );
+        match errors {
+            | Some(ref mut errors) => errors.combine(err),
+            | ref mut it @ None => *it = Some(err),
+        }
+    }
+    errors.map_or(Ok(warnings), Err)
+}
+
+/// The bounds of the generic params of the `impl`, and its `where` clauses.
+fn impl_predicates (impl_: &'_ ItemImpl)
+  -> Vec<WherePredicate>
+{
+    let mut ret: Vec<WherePredicate> = vec![];
+    for param in &impl_.generics.params {
+        match *param {
+            | GenericParam::Type(TypeParam { ref ident, ref bounds, .. })
+                if bounds.is_empty().not()
+            => {
+                ret.push(parse_quote!( #ident : #bounds ));
+            },
+            | GenericParam::Lifetime(LifetimeDef { ref lifetime, ref bounds, .. })
+                if bounds.is_empty().not()
+            => {
+                ret.push(parse_quote!( #lifetime : #bounds ));
+            },
+            | _ => {},
+        }
+    }
+    ret.extend(
+        impl_.generics.where_clause.iter().flat_map(|it| it.predicates.iter().cloned())
+    );
+    ret
+}
+
+/// Each `Ty : Bound` (or `'a : 'b`, or `Ty = Ty`) predicate, with a single
+/// bound, and normalized (see `Normalize`) to be compared with `==`,
+/// alongside the predicate as written, to report.
+fn each_bound (
+    Implementor: &'_ Type,
+    super_types: &'_ [(Lifetime, Option<Type>)],
+    where_predicates: &'_ [WherePredicate],
+) -> Vec<(WherePredicate, TokenStream2)>
+{
+    let mut ret: Vec<(WherePredicate, TokenStream2)> = vec![];
+    for (lt, SuperTy) in super_types {
+        let SuperTy = SuperTy.clone().unwrap_or_else(|| parse_quote!( Self ));
+        ret.push((parse_quote!( #SuperTy : #lt ), quote!( #SuperTy : #lt )));
+    }
+    for predicate in where_predicates {
+        match *predicate {
+            | WherePredicate::Type(PredicateType {
+                ref lifetimes,
+                ref bounded_ty,
+                ref bounds,
+                ..
+            }) => {
+                for bound in bounds {
+                    // `for<'x> Ty : Trait<'x>` is `Ty : for<'x> Trait<'x>`.
+                    let mut normalized = bound.clone();
+                    if let TypeParamBound::Trait(ref mut it) = normalized {
+                        if it.lifetimes.is_none() {
+                            it.lifetimes = lifetimes.clone();
+                        }
+                    }
+                    ret.push((
+                        parse_quote!( #bounded_ty : #normalized ),
+                        quote!( #lifetimes #bounded_ty : #bound ),
+                    ));
+                }
+            },
+            | WherePredicate::Lifetime(PredicateLifetime {
+                ref lifetime,
+                ref bounds,
+                ..
+            }) => {
+                for bound in bounds {
+                    ret.push((
+                        parse_quote!( #lifetime : #bound ),
+                        quote!( #lifetime : #bound ),
+                    ));
+                }
+            },
+            | WherePredicate::Eq(ref it) => {
+                ret.push((WherePredicate::Eq(it.clone()), quote!( #it )));
+            },
+        }
+    }
+    let ref mut normalize = Normalize { Implementor };
+    for (normalized, _) in &mut ret {
+        visit_mut::VisitMut::visit_where_predicate_mut(normalize, normalized);
+    }
+    ret
+}
+
+/// The type (or lifetime) bounded by a predicate of `each_bound()`.
+fn bounded (predicate: &'_ WherePredicate)
+  -> GenericArgument
+{
+    match *predicate {
+        | WherePredicate::Type(PredicateType { ref bounded_ty, .. }) => {
+            GenericArgument::Type(bounded_ty.clone())
+        },
+        | WherePredicate::Lifetime(PredicateLifetime { ref lifetime, .. }) => {
+            GenericArgument::Lifetime(lifetime.clone())
+        },
+        | WherePredicate::Eq(PredicateEq { ref lhs_ty, .. }) => {
+            GenericArgument::Type(lhs_ty.clone())
+        },
+    }
+}
+
+/// The `Implementor` type becomes `Self`, the parentheses (and invisible
+/// groups) around types are removed, and the paths to the traits are reduced
+/// to their last segment, so that `T : ::core::clone::Clone` and `T : Clone`
+/// compare equal (a trait path, contrary to a type one, cannot be relative to
+/// a type, as in `T::Assoc`).
+struct Normalize<'implementor> {
+    Implementor: &'implementor Type,
+}
+
+impl visit_mut::VisitMut for Normalize<'_> {
+    fn visit_type_mut (
+        self: &'_ mut Self,
+        ty: &'_ mut Type,
+    )
+    {
+        loop {
+            *ty = match *ty {
+                | Type::Group(TypeGroup { ref elem, .. })
+                | Type::Paren(TypeParen { ref elem, .. })
+                => (**elem).clone(),
+                | _ => break,
+            };
+        }
+        if *ty == *self.Implementor {
+            *ty = parse_quote!( Self );
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty); // subrecurse
+    }
+
+    fn visit_trait_bound_mut (
+        self: &'_ mut Self,
+        trait_bound: &'_ mut TraitBound,
+    )
+    {
+        visit_mut::visit_trait_bound_mut(self, trait_bound); // subrecurse
+        let path = &mut trait_bound.path;
+        let last = path.segments.pop().unwrap().into_value();
+        *path = last.into();
+    }
+}
+
+/// The trait-bound `where` clauses of a lifetime GAT (_e.g._,
+/// `type Copied<'a> where T : Copy`) end up on its helper `impl`, which the
+/// trait requires for every lifetime (it is a `for<'a>` supertrait): rather
+/// than only applying where the GAT is used, they thus have to hold for the
+/// whole `impl`. Report it (and drop them) when they mention a type parameter
+/// of the `impl` which the `impl` does not bound accordingly (_e.g._,
+/// `impl<T> Views<T> for Wrapper<T>`), rather than letting `rustc` complain
+/// about the helper traits.
+fn check_impl_wide_predicates (
+    impl_: &'_ ItemImpl,
+    lgat: &'_ mut LGat,
+)
+{
+    let ty_params: Vec<&Ident> =
+        impl_.generics.type_params().map(|it| &it.ident).collect()
+    ;
+    if ty_params.is_empty() || lgat.where_predicates.is_empty() {
+        return;
+    }
+    let Implementor @ _ = &*impl_.self_ty;
+    let impl_bounds = each_bound(Implementor, &[], &impl_predicates(impl_));
+    let Assoc = &lgat.ident;
+    lgat.where_predicates.retain(|predicate| {
+        let bounded_ty = match *predicate {
+            | WherePredicate::Type(PredicateType { ref bounded_ty, .. }) => bounded_ty,
+            | _ => return true,
+        };
+        if mentions_any(bounded_ty.to_token_stream(), &ty_params).not() {
+            return true;
+        }
+        let mut ret = true;
+        for (normalized, predicate) in
+            each_bound(Implementor, &[], ::core::slice::from_ref(predicate))
+        {
+            if impl_bounds.iter().any(|(it, _)| *it == normalized) {
+                continue;
+            }
+            ret = false;
+            utils::recover(Error::new_spanned(
+                &predicate,
+                format_args!(
+                    "`{pred}` has to hold for the whole `impl`: since a lifetime \
+                    GAT is required by its trait for every lifetime, its \
+                    (non-outlives) `where` clauses cannot be conditional. \
+                    Add `{pred}` to the bounds of the `impl`, or remove it from \
+                    `type {}`",
+                    Assoc,
+                    pred = predicate,
+                ),
+            ));
+        }
+        ret
+    });
+}
+
+/// Whether the tokens mention one of the given identifiers.
+fn mentions_any (
+    tokens: TokenStream2,
+    idents: &'_ [&'_ Ident],
+) -> bool
+{
+    tokens.into_iter().any(|tt| match tt {
+        | TT::Ident(ref ident) => idents.contains(&ident),
+        | TT::Group(group) => mentions_any(group.stream(), idents),
+        | _ => false,
+    })
+}
+
+/// The omitted defaulted GATs are added to the `impl` block, as if the user had
+/// written them, with the generic parameters of the trait (in the default)
+/// replaced by the generic arguments of the `impl`.
+fn add_omitted_defaults (
+    impl_: &'_ mut ItemImpl,
+    PathToTrait: &'_ Path,
+    metadata: &'_ TraitMetadata,
+) -> Result<()>
+{
+    let defaults: Vec<&TraitItemType> =
+        metadata
+            .gats()
+            .filter(|gat| gat.default.is_some())
+            .filter(|gat| impl_.items.iter().any(|item| matches!(
+                *item, ImplItem::Type(ImplItemType { ref ident, .. })
+                if *ident == gat.ident
+            )).not())
+            .collect()
+    ;
+    if defaults.is_empty() {
+        return Ok(());
+    }
+    let ref mut substitute = SubstituteTraitGenerics::new(
+        PathToTrait,
+        &metadata.trait_.generics,
+        parse_quote!( Self ),
+    )?;
+    for gat in defaults {
+        let TraitItemType { ident, generics, default, .. } = gat;
+        let (_eq, ty) = default.as_ref().unwrap();
+        let mut assoc_ty: ImplItemType = parse_quote!(
+            type #ident #generics = #ty;
+        );
+        assoc_ty.generics.where_clause = generics.where_clause.clone();
+        visit_mut::VisitMut::visit_impl_item_type_mut(
+            substitute,
+            &mut assoc_ty,
+        );
+        impl_.items.push(ImplItem::Type(assoc_ty));
+    }
+    Ok(())
+}
+
+/// Rather than letting `rustc` complain about some missing (or mismatched)
+/// `TraitඞAssoc` helper, check that the `impl` provides every GAT of the trait
+/// (_e.g._, `missing GAT `Item<'next>` required by `LendingIterator``), with
+/// the right number of lifetime, type and `const` parameters.
+///
+/// The missing or mismatched GATs are then stubbed (as `()`), and the unknown
+/// ones removed, so that the rest of the `impl` yields no further errors.
+fn check_gats (
+    impl_: &'_ mut ItemImpl,
+    PathToTrait: &'_ Path,
+    metadata: &'_ TraitMetadata,
+) -> Result<()>
+{
+    let TraitName @ _ = &PathToTrait.segments.last().unwrap().ident;
+    let arities = |generics: &Generics| (
+        generics.lifetimes().count(),
+        generics.type_params().count(),
+        generics.const_params().count(),
+    );
+    let mut errors: Option<Error> = None;
+    let mut report = |err: Error| match errors {
+        | Some(ref mut errors) => errors.combine(err),
+        | ref mut it @ None => *it = Some(err),
+    };
+    let stub = |gat: &TraitItemType| -> ImplItem {
+        let TraitItemType { ref ident, ref generics, .. } = *gat;
+        let mut stub: ImplItemType = parse_quote!(
+            type #ident = ();
+        );
+        // Only the parameters: the bounds may name the trait's own generics.
+        stub.generics.params =
+            generics.params.iter().map(|param| match *param {
+                | GenericParam::Lifetime(LifetimeDef { ref lifetime, .. }) => {
+                    GenericParam::Lifetime(LifetimeDef::new(lifetime.clone()))
+                },
+                | GenericParam::Type(TypeParam { ref ident, .. }) => {
+                    GenericParam::Type(ident.clone().into())
+                },
+                | GenericParam::Const(ref it) => GenericParam::Const(
+                    ConstParam { default: None, ..it.clone() }
+                ),
+            })
+            .collect()
+        ;
+        ImplItem::Type(stub)
+    };
+    for gat in metadata.gats() {
+        let impl_gat = impl_.items.iter_mut().find_map(|item| match *item {
+            | ImplItem::Type(ref mut it) if it.ident == gat.ident => Some(it),
+            | _ => None,
+        });
+        let impl_gat = match impl_gat {
+            | Some(it) => it,
+            | None => {
+                let ItemImpl { impl_token, generics, trait_, self_ty, .. } =
+                    &*impl_
+                ;
+                let (_, _, for_) = trait_.as_ref().unwrap();
+                report(Error::new_spanned(
+                    quote!( #impl_token #generics #PathToTrait #for_ #self_ty ),
+                    format_args!(
+                        "missing GAT `{}` required by `{}`",
+                        gat_signature(gat), TraitName,
+                    ),
+                ));
+                impl_.items.push(stub(gat));
+                continue;
+            },
+        };
+        let expected = arities(&gat.generics);
+        let found = arities(&impl_gat.generics);
+        if found == expected {
+            continue;
+        }
+        let mismatches =
+            [
+                (expected.0, found.0, "lifetime"),
+                (expected.1, found.1, "type"),
+                (expected.2, found.2, "`const`"),
+            ]
+            .iter()
+            .filter(|&&(expected, found, _)| expected != found)
+            .map(|&(expected, found, kind)| format!(
+                "{} {} parameter{} instead of {}",
+                found, kind, if found == 1 { "" } else { "s" }, expected,
+            ))
+            .collect::<Vec<_>>()
+        ;
+        let ref params = impl_gat.generics.params;
+        report(Error::new_spanned(
+            if params.is_empty() {
+                impl_gat.ident.to_token_stream()
+            } else {
+                params.to_token_stream()
+            },
+            format_args!(
+                "expected `{}`, as declared by `{}`: found {}",
+                gat_signature(gat), TraitName, mismatches.join(", "),
+            ),
+        ));
+        if let ImplItem::Type(it) = stub(gat) {
+            *impl_gat = ImplItemType { ident: impl_gat.ident.clone(), ..it };
+        }
+    }
+    // The (generic) associated types which the trait does not declare.
+    impl_.items.retain(|item| match *item {
+        | ImplItem::Type(ImplItemType { ref ident, ref generics, .. })
+            if generics.params.is_empty().not()
+            && metadata.gats().any(|gat| gat.ident == *ident).not()
+        => {
+            report(Error::new_spanned(
+                ident,
+                format_args!("no GAT named `{}` in `{}`", ident, TraitName),
+            ));
+            false
+        },
+        | _ => true,
+    });
+    errors.map_or(Ok(()), Err)
+}
+
+/// `Assoc<'lt, T, const N: usize>`, for diagnostics.
+fn gat_signature (gat: &'_ TraitItemType)
+  -> String
+{
+    let params =
+        gat .generics
+            .params
+            .iter()
+            .map(|param| match *param {
+                | GenericParam::Lifetime(LifetimeDef { ref lifetime, .. }) => {
+                    lifetime.to_string()
+                },
+                | GenericParam::Type(TypeParam { ref ident, .. }) => {
+                    ident.to_string()
+                },
+                | GenericParam::Const(ConstParam { ref ident, ref ty, .. }) => {
+                    format!("const {}: {}", ident, ty.to_token_stream())
+                },
+            })
+            .collect::<Vec<_>>()
+    ;
+    format!("{}<{}>", gat.ident, params.join(", "))
+}
+
+/// `fn upcast_assoc<'short, 'long : 'short> (it: Self::Assoc<'long>) -> …`
+/// gets the identity as its body, unless the `impl` already provides it.
+fn add_upcasts (
+    impl_: &'_ mut ItemImpl,
+    metadata: &'_ TraitMetadata,
+) -> Result<()>
+{
+    for gat in metadata.gats() {
+        let mut covariant = false;
+        for attr in &gat.attrs {
+            covariant |= is_covariant_marker(attr)?;
+        }
+        if covariant.not() {
+            continue;
+        }
+        let upcast_signature = upcast_signature(&gat.ident, &gat.generics);
+        let upcast: ImplItemMethod = parse_quote!(
+            #upcast_signature
+            {
+                it
+            }
+        );
+        if impl_.items.iter().any(|item| matches!(
+            *item, ImplItem::Method(ImplItemMethod { ref sig, .. })
+            if sig.ident == upcast.sig.ident
+        ))
+        {
+            continue;
+        }
+        impl_.items.push(ImplItem::Method(upcast));
+    }
+    Ok(())
+}
+
 impl LGat {
     fn from_trait_impl (assoc_ty: ImplItemType)
-      -> Result<LGat>
+      -> LGat
     {
         let ImplItemType {
             attrs, ident, generics, ty,
             vis, defaultness,
             type_token: _, eq_token: _, semi_token: _,
         } = assoc_ty;
+        // Not supported: reported, and otherwise ignored.
         if matches!(vis, Visibility::Inherited).not() {
-            bail!("not supported" => vis);
+            utils::recover(Error::new_spanned(vis, "not supported"));
         }
-        if defaultness.is_some() {
-            bail!("not supported" => defaultness);
+        if let Some(default_) = defaultness {
+            utils::recover(Error::new_spanned(default_, "not supported"));
         }
-        let (generic_lifetimes, super_types) = Self::parse_generics(generics)?;
-        Ok(LGat {
+        let (
+            generic_lifetimes,
+            generic_tys_and_consts,
+            super_types,
+            where_predicates,
+        ) = Self::parse_generics_or_stub(generics);
+        LGat {
             attrs,
             ident,
             bounds: Punctuated::new(),
             generic_lifetimes,
+            generic_tys_and_consts,
             super_types,
+            where_predicates,
             value: Some(ty),
-        })
+        }
     }
 }
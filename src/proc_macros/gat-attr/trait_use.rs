@@ -4,93 +4,179 @@ pub(super) fn handle(
     assoc_type_use: ItemUse,
     assoc_types: &Punctuated<Ident, Token![,]>,
 ) -> Result<TokenStream2> {
-    let (use_segments, name_type) = find_use_path_and_name(Vec::new(), &assoc_type_use.tree)?;
-
-    let assoc_typenames =
-        assoc_types
-        .iter()
-        .try_fold::<_, _, std::result::Result<_, Error>>(
-            Vec::<TokenStream2>::with_capacity(assoc_types.len()),
-            |mut assoc_uses, assoc_type| {
-                match name_type {
-                    NameType::Name(use_name) => {
-                        // Push a list of `TraitඞData`
-                        let trait_name = &use_name.ident;
-                        let assoc_typename =
-                            combine_trait_name_and_assoc_type(trait_name, assoc_type);
-
-                        assoc_uses.push(quote!(#assoc_typename));
-                    },
-                    NameType::Rename(use_rename) => {
-                        // Push a list of `TraitඞData as TraitRenamedඞData`
-                        let trait_name = &use_rename.ident;
-                        let trait_rename = &use_rename.rename;
-
-                        let assoc_typename =
-                            combine_trait_name_and_assoc_type(trait_name, assoc_type);
-                        let assoc_typename_rename =
-                            combine_trait_name_and_assoc_type(trait_rename, assoc_type);
+    let mut leaves = Vec::new();
+    let has_glob = collect_use_paths_and_names(Vec::new(), &assoc_type_use.tree, &mut leaves);
+    if assoc_types.is_empty().not() && (has_glob || leaves.len() != 1) {
+        bail!("expected a single item in this import, e.g.\n\
+            `use path::to::Trait`, or `use path::to::Trait as Renamed`\n\
+            (or no GATs listed, as in `#[gat] use path::to::{Trait, Other};`)"
+            => assoc_type_use.tree)
+    }
 
-                        assoc_uses.push(quote!(#assoc_typename as #assoc_typename_rename));
-                    },
+    let pub_ = &assoc_type_use.vis;
+    let leading_colon = &assoc_type_use.leading_colon;
+    let mut ret = assoc_type_use.to_token_stream();
+    // Glob imports already bring the helpers (and the `TraitඞGATs!` macro)
+    // along; the other items have their helpers re-exported next to them.
+    for (use_segments, name_type) in leaves {
+        if assoc_types.is_empty() {
+            // Fetch the GATs of the trait through its `TraitඞGATs!` macro, which
+            // shall then call back into `gat_use()`, with this single item.
+            // That macro is thus the only path resolved here, so that a leaf
+            // which is not a `#[gat]` trait yields a single error, about it.
+            let (trait_name, leaf) = match name_type {
+                NameType::Name(use_name) => (&use_name.ident, use_name.to_token_stream()),
+                NameType::Rename(use_rename) => (&use_rename.ident, use_rename.to_token_stream()),
+            };
+            let Trait_GATs = combine_trait_name_into_gats_macro(trait_name);
+            ret.extend(quote! {
+                #leading_colon #(#use_segments ::)* #Trait_GATs! {
+                    [__gat_use]
+                    #pub_ use #leading_colon #(#use_segments :: )* #leaf;
                 }
+            });
+            continue;
+        }
+        let assoc_typenames = reexported_helpers(
+            name_type,
+            assoc_types.iter().map(|it| (it, false)),
+        );
+        let gats_macro = reexported_gats_macro(name_type);
+        ret.extend(quote! {
+            #[doc(hidden)] /** Not part of the public API */
+            #pub_ use #leading_colon #(#use_segments :: )* { #(#assoc_typenames,)* #gats_macro };
+        });
+    }
+    Ok(ret)
+}
 
-                Ok(assoc_uses)
-            },
-        )?
-    ;
-
+/// `{ <TraitMetadata> } <use…>`, as emitted by `TraitඞGATs!` on behalf of a
+/// `#[gat] use` not listing the GATs: re-export the helpers of each GAT (and
+/// the `TraitඞGATs!` macro itself).
+pub(in super)
+fn handle_fetched (
+    metadata: TraitMetadata,
+    assoc_type_use: ItemUse,
+) -> Result<TokenStream2>
+{
+    let mut leaves = Vec::new();
+    collect_use_paths_and_names(Vec::new(), &assoc_type_use.tree, &mut leaves);
+    let (use_segments, name_type) = leaves.pop().unwrap();
+    let is_dyn = metadata.dyn_.is_some();
+    let assoc_typenames = reexported_helpers(
+        name_type,
+        metadata.gats().map(|gat| (&gat.ident, is_dyn)),
+    );
+    let gats_macro = reexported_gats_macro(name_type);
     let pub_ = &assoc_type_use.vis;
+    let leading_colon = &assoc_type_use.leading_colon;
     Ok(quote! {
-        #assoc_type_use
-
         #[doc(hidden)] /** Not part of the public API */
-        #pub_ use #(#use_segments :: )* { #(#assoc_typenames,)* };
+        #pub_ use #leading_colon #(#use_segments :: )* { #(#assoc_typenames,)* #gats_macro };
     })
 }
 
-fn find_use_path_and_name<'item>(
-    mut accumulated_path: Vec<&'item Ident>,
+/// The `TraitඞGATs!` metadata macro (or its renaming), for `#[gat] impl`s to
+/// use.
+fn reexported_gats_macro (
+    name_type: NameType<'_>,
+) -> TokenStream2
+{
+    match name_type {
+        NameType::Name(use_name) => {
+            let gats_macro = combine_trait_name_into_gats_macro(&use_name.ident);
+            quote!(#gats_macro)
+        },
+        NameType::Rename(use_rename) => {
+            let gats_macro = combine_trait_name_into_gats_macro(&use_rename.ident);
+            let gats_macro_rename = combine_trait_name_into_gats_macro(&use_rename.rename);
+            quote!(#gats_macro as #gats_macro_rename)
+        },
+    }
+}
+
+/// `TraitඞAssoc` (or `TraitඞAssoc as RenamedඞAssoc`) for each GAT, as well as
+/// `TraitඞAssocඞHKT` (or its renaming) for the `#[gat(dyn)]` ones.
+fn reexported_helpers<'gat> (
+    name_type: NameType<'_>,
+    assoc_types: impl Iterator<Item = (&'gat Ident, /* is_dyn: */ bool)>,
+) -> Vec<TokenStream2>
+{
+    let mut assoc_uses = vec![];
+    for (assoc_type, is_dyn) in assoc_types {
+        let mut combiners: Vec<fn(&Ident, &Ident) -> Ident> = vec![
+            combine_trait_name_and_assoc_type,
+        ];
+        if is_dyn {
+            combiners.push(combine_trait_name_and_assoc_type_into_hkt);
+        }
+        for combine in combiners {
+            match name_type {
+                NameType::Name(use_name) => {
+                    // Push a list of `TraitඞData`
+                    let trait_name = &use_name.ident;
+                    let assoc_typename = combine(trait_name, assoc_type);
+
+                    assoc_uses.push(quote!(#assoc_typename));
+                },
+                NameType::Rename(use_rename) => {
+                    // Push a list of `TraitඞData as TraitRenamedඞData`
+                    let trait_name = &use_rename.ident;
+                    let trait_rename = &use_rename.rename;
+
+                    let assoc_typename = combine(trait_name, assoc_type);
+                    let assoc_typename_rename = combine(trait_rename, assoc_type);
+
+                    assoc_uses.push(quote!(#assoc_typename as #assoc_typename_rename));
+                },
+            }
+        }
+    }
+    assoc_uses
+}
+
+/// Collects the path to, and the name of, each imported item (_e.g._,
+/// `use path::{to::Trait, Other as Renamed};`).
+///
+/// Returns whether the import has globs, whose helpers (and `TraitඞGATs!`
+/// macro) are brought along by the glob itself.
+fn collect_use_paths_and_names<'item>(
+    accumulated_path: Vec<&'item Ident>,
     use_tree: &'item UseTree,
-) -> Result<(Vec<&'item Ident>, NameType<'item>)> {
+    out: &mut Vec<(Vec<&'item Ident>, NameType<'item>)>,
+) -> bool {
     match use_tree {
         UseTree::Path(use_path) => {
+            let mut accumulated_path = accumulated_path;
             accumulated_path.push(&use_path.ident);
-            find_use_path_and_name(accumulated_path, &use_path.tree)
-        }
-        UseTree::Name(use_name) => Ok((accumulated_path, NameType::Name(use_name))),
+            collect_use_paths_and_names(accumulated_path, &use_path.tree, out)
+        },
+        // `use path::to::module::{self};` imports no trait.
+        UseTree::Name(UseName { ident }) | UseTree::Rename(UseRename { ident, .. })
+            if ident == "self" =>
+        {
+            false
+        },
+        UseTree::Name(use_name) => {
+            out.push((accumulated_path, NameType::Name(use_name)));
+            false
+        },
         UseTree::Rename(use_rename) => {
-            // Support a single renamed trait without a group, e.g.
-            //
-            // ```rust
-            // #[gat(Item)]
-            // use lib_crate::Trait as Renamed;
-            // ```
-            Ok((accumulated_path, NameType::Rename(use_rename)))
+            out.push((accumulated_path, NameType::Rename(use_rename)));
+            false
         },
         UseTree::Group(use_group) => {
-            // Only support a single renamed trait in a use-group, e.g.
-            //
-            // ```rust
-            // #[gat(Item)]
-            // use lib_crate::{Trait as Renamed};
-            // ```
-
-            if use_group.items.len() == 1 {
-                if let Some(UseTree::Rename(use_rename)) = use_group.items.first() {
-                    return Ok((accumulated_path, NameType::Rename(use_rename)));
-                }
+            let mut has_glob = false;
+            for use_tree in &use_group.items {
+                has_glob |= collect_use_paths_and_names(accumulated_path.clone(), use_tree, out);
             }
-            bail!("expected a single item in this import, e.g.\n\
-                `use path::to::Trait`, or `use path::to::Trait as Renamed`" => use_group)
-        }
-        UseTree::Glob(glob) => {
-            bail!("expected a single item in this import, e.g.\n\
-                    `use path::to::Trait`, or `use path::to::Trait as Renamed`" => glob)
-        }
+            has_glob
+        },
+        UseTree::Glob(_) => true,
     }
 }
 
+#[derive(Clone, Copy)]
 enum NameType<'item> {
     Name(&'item UseName),
     Rename(&'item UseRename),
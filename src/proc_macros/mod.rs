@@ -34,6 +34,9 @@ mod adjugate;
 #[path = "Gat-bang.rs"]
 mod Gat;
 
+#[path = "gat-attr/_mod.rs"]
+mod gat;
+
 use self::utils::*;
 mod utils;
 
@@ -45,9 +48,73 @@ fn gat (
 ) -> TokenStream
 {
     unwrap("#[::nougat::gat]", {
-        #[path = "gat-attr/_mod.rs"]
-        mod implementation;
-        implementation::gat(attrs.into(), input.into())
+        gat::gat(attrs.into(), input.into())
+    })
+}
+
+/// Not part of the public API: the second half of a `#[gat] impl`, once the
+/// metadata of the implemented `trait` has been fetched.
+#[doc(hidden)]
+#[proc_macro] pub
+fn __gat_impl (
+    input: TokenStream,
+) -> TokenStream
+{
+    unwrap("#[::nougat::gat]", {
+        gat::gat_impl(input.into())
+    })
+}
+
+/// Not part of the public API: the second half of a `#[gat] use` not listing
+/// the GATs, once the metadata of the `trait` has been fetched.
+#[doc(hidden)]
+#[proc_macro] pub
+fn __gat_use (
+    input: TokenStream,
+) -> TokenStream
+{
+    unwrap("#[::nougat::gat]", {
+        gat::gat_use(input.into())
+    })
+}
+
+/// Not part of the public API: the second half of a `#[gat(Self : …)]`, once
+/// the metadata of each `trait` has been fetched.
+#[doc(hidden)]
+#[proc_macro] pub
+fn __gat_self (
+    input: TokenStream,
+) -> TokenStream
+{
+    unwrap("#[::nougat::gat]", {
+        gat::gat_self(input.into())
+    })
+}
+
+/// Not part of the public API: the second half of a `dyn Trait<Assoc<…> = …>`
+/// type, once the metadata of the `trait` has been fetched.
+#[doc(hidden)]
+#[proc_macro] pub
+fn __gat_dyn (
+    input: TokenStream,
+) -> TokenStream
+{
+    unwrap("::nougat::Gat!", {
+        Gat::trait_object(input.into())
+    })
+}
+
+/// Not part of the public API: the second half of a nested `Gat!` path
+/// (`<X as Trait>::Assoc<…>::Nested`), once the metadata of the `trait` has
+/// been fetched.
+#[doc(hidden)]
+#[proc_macro] pub
+fn __gat_nested (
+    input: TokenStream,
+) -> TokenStream
+{
+    unwrap("::nougat::Gat!", {
+        Gat::nested(input.into())
     })
 }
 
@@ -72,3 +139,40 @@ fn combine_trait_name_and_assoc_type (
         assoc_type.span(), // .located_at(trait_name.span()),
     )
 }
+
+/// `#[gat(dyn)]`'s `WithLifetime`-like trait.
+fn combine_trait_name_and_assoc_type_into_hkt (
+    trait_name: &'_ Ident,
+    assoc_type: &'_ Ident,
+) -> Ident
+{
+    Ident::new(
+        &format!("{}ඞ{}ඞHKT", trait_name, assoc_type),
+        assoc_type.span(),
+    )
+}
+
+/// The name under which the helper traits of the GATs which a `trait`
+/// inherits from a supertrait are re-exported (as `TraitඞSuperඞAssoc`), when
+/// the supertrait is given other generic args than the `trait`'s own.
+fn combine_trait_name_into_super (
+    trait_name: &'_ Ident,
+) -> Ident
+{
+    Ident::new(
+        &format!("{}ඞSuper", trait_name),
+        trait_name.span(),
+    )
+}
+
+/// The `TraitඞGATs!` macro, through which a `#[gat] impl` learns about the
+/// `#[gat] trait` it implements.
+fn combine_trait_name_into_gats_macro (
+    trait_name: &'_ Ident,
+) -> Ident
+{
+    Ident::new(
+        &format!("{}ඞGATs", trait_name),
+        trait_name.span(),
+    )
+}
@@ -8,20 +8,67 @@ fn unwrap (
     result: Result<TokenStream2>,
 ) -> TokenStream
 {
-    result
-        .unwrap_or_else(|err| {
-            let mut errors =
-                err .into_iter()
-                    .map(|err| Error::new(
-                        err.span(),
-                        format_args!("`{}`: {}", macro_name, err),
-                    ))
-            ;
-            let mut err = errors.next().unwrap();
-            errors.for_each(|cur| err.combine(cur));
-            err.to_compile_error()
-        })
-        .into()
+    // The errors which have been recovered from (see `recover()`) are emitted
+    // next to the (best-effort) expansion.
+    let recovered = RECOVERED.with(|it| it.borrow_mut().take());
+    let (mut ret, errors) = match (result, recovered) {
+        | (Ok(ret), recovered) => (ret, recovered),
+        | (Err(err), None) => (quote!(), Some(err)),
+        | (Err(err), Some(mut errors)) => {
+            errors.combine(err);
+            (quote!(), Some(errors))
+        },
+    };
+    if let Some(errors) = errors {
+        let mut errors =
+            errors
+                .into_iter()
+                .map(|err| Error::new(
+                    err.span(),
+                    format_args!("`{}`: {}", macro_name, err),
+                ))
+        ;
+        let mut err = errors.next().unwrap();
+        errors.for_each(|cur| err.combine(cur));
+        ret.extend(err.to_compile_error());
+    }
+    ret.into()
+}
+
+::std::thread_local! {
+    static RECOVERED: ::core::cell::RefCell<Option<Error>> = <_>::default();
+}
+
+/// Reports an error without giving up on the expansion: rather than having the
+/// whole item vanish (and every use of it error in turn), the caller goes on
+/// with a best-effort expansion (_e.g._, with the offending GAT stubbed), and
+/// `unwrap()` emits the (combined) errors next to it.
+pub(in crate)
+fn recover (err: Error)
+{
+    RECOVERED.with(|it| match *it.borrow_mut() {
+        | Some(ref mut errors) => errors.combine(err),
+        | ref mut it @ None => *it = Some(err),
+    })
+}
+
+/// What to put in place of a type path which could not be resolved, once the
+/// error has been reported (see `recover()`), so that it does not cascade into
+/// further errors (_e.g._, about the helper trait not being in scope).
+pub(in crate)
+fn stub_type_path (spanned: &'_ dyn ToTokens)
+  -> TypePath
+{
+    let span =
+        spanned .to_token_stream()
+                .into_iter()
+                .next()
+                .map_or_else(Span::call_site, |tt| tt.span())
+    ;
+    let Infallible @ _ = quote_spanned!(span=>
+        ::core::convert::Infallible
+    );
+    parse_quote!(#Infallible)
 }
 
 macro_rules! bail {
@@ -34,6 +81,22 @@ macro_rules! bail {
     );
 } pub(in super) use bail;
 
+/// Whether the path names one of the (stable) auto traits of the standard
+/// library: these cannot have associated items, and can thus be skipped when
+/// looking for the trait a GAT belongs to (_e.g._, `I : LendingIterator + Send`
+/// for an `I::Item<'_>`). Any other trait is taken into account, for it may
+/// have a GAT of the same name.
+pub(in crate)
+fn is_auto_trait (trait_: &'_ Path)
+  -> bool
+{
+    const AUTO_TRAITS: &[&str] = &[
+        "RefUnwindSafe", "Send", "Sync", "Unpin", "UnwindSafe",
+    ];
+    let last_ident = &trait_.segments.last().unwrap().ident;
+    AUTO_TRAITS.iter().any(|it| last_ident == it)
+}
+
 pub
 trait DrainFilterExt {
     type Item;
@@ -65,6 +128,66 @@ impl<T> DrainFilterExt for Vec<T> {
     }
 }
 
+/// Lifetime params are always the leading ones.
+pub(in crate)
+fn count_lifetime_params (
+    params: &'_ Punctuated<GenericParam, Token![,]>,
+) -> usize
+{
+    params
+        .iter()
+        .take_while(|it| matches!(it, GenericParam::Lifetime { .. }))
+        .count()
+}
+
+/// Lifetime args are always the leading ones.
+pub(in crate)
+fn count_lifetime_args (
+    args: &'_ Punctuated<GenericArgument, Token![,]>,
+) -> usize
+{
+    args.iter()
+        .take_while(|it| matches!(it, GenericArgument::Lifetime { .. }))
+        .count()
+}
+
+/// `<'a, T : Bound, const N: usize>` to `<'a, T, N>`.
+pub(in crate)
+fn generic_args_of (
+    generics: &'_ Generics,
+) -> Punctuated<GenericArgument, Token![,]>
+{
+    generics
+        .params
+        .iter()
+        .map(|param| -> GenericArgument { match *param {
+            | GenericParam::Lifetime(LifetimeDef { ref lifetime, .. }) => {
+                parse_quote!( #lifetime )
+            },
+            | GenericParam::Type(TypeParam { ref ident, .. })
+            | GenericParam::Const(ConstParam { ref ident, .. }) => {
+                parse_quote!( #ident )
+            },
+        }})
+        .collect()
+}
+
+/// Collects every lifetime it visits.
+pub(in crate)
+struct CollectLifetimes /* = */ (
+    pub(in crate) Vec<Lifetime>,
+);
+
+impl visit_mut::VisitMut for CollectLifetimes {
+    fn visit_lifetime_mut (
+        self: &'_ mut CollectLifetimes,
+        lifetime: &'_ mut Lifetime,
+    )
+    {
+        self.0.push(lifetime.clone());
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! dbg_parse_quote {(
     $($code:tt)*
@@ -95,7 +218,7 @@ fn compile_warning (
 ) -> TokenStream2
 {
     let mut spans = span.to_token_stream().into_iter().map(|tt| tt.span());
-    let fst = spans.next().unwrap_or_else(|| Span::call_site());
+    let fst = spans.next().unwrap_or_else(Span::call_site);
     let lst = spans.fold(fst, |cur, _| cur);
     let nougat_ = Ident::new("nougat_", fst);
     let warning = Ident::new("warning", lst);
@@ -367,3 +367,1621 @@ impl<X, T> Foo<T> for X
 where
     Self : for<'n> LendingIterator<Item<'n> = &'n mut [T; 2]>,
 {}
+
+#[gat]
+trait Collection {
+    type Of<T>
+    :
+        IntoIterator<Item = T>
+    ;
+
+    fn empty<T> ()
+      -> Self::Of<T>
+    ;
+
+    fn singleton<T> (value: T)
+      -> Self::Of<T>
+    ;
+}
+
+enum Vec_ {}
+
+#[gat]
+impl Collection for Vec_ {
+    type Of<T> = Vec<T>;
+
+    fn empty<T> ()
+      -> Self::Of<T>
+    {
+        Vec::new()
+    }
+
+    fn singleton<T> (value: T)
+      -> Self::Of<T>
+    {
+        vec![value]
+    }
+}
+
+enum Option_ {}
+
+#[gat]
+impl Collection for Option_ {
+    type Of<T> = Option<T>;
+
+    fn empty<T> ()
+      -> Self::Of<T>
+    {
+        None
+    }
+
+    fn singleton<T> (value: T)
+      -> Self::Of<T>
+    {
+        Some(value)
+    }
+}
+
+#[apply(Gat!)]
+fn singletons<C : Collection> ()
+  -> (<C as Collection>::Of<u8>, <C as Collection>::Of<bool>)
+{
+    (C::singleton(42), C::singleton(true))
+}
+
+#[test]
+fn type_gats ()
+{
+    let (ints, bools): (Vec<u8>, Vec<bool>) = singletons::<Vec_>();
+    assert_eq!((ints, bools), (vec![42], vec![true]));
+    let _: Gat!(<Option_ as Collection>::Of<()>) = Option_::empty();
+    assert_eq!(singletons::<Option_>().0.into_iter().sum::<u8>(), 42);
+}
+
+#[gat]
+trait Buffer {
+    type Chunk<const N: usize>;
+
+    type Window<'a, const N: usize>
+    where
+        Self : 'a,
+    ;
+
+    fn chunk<const N: usize> (self: &'_ Self)
+      -> Option<Self::Chunk<N>>
+    ;
+
+    fn window<'a, const N: usize> (self: &'a mut Self, start: usize)
+      -> Option<Self::Window<'a, N>>
+    ;
+}
+
+struct Bytes(Vec<u8>);
+
+#[gat]
+impl Buffer for Bytes {
+    type Chunk<const N: usize> = [u8; N];
+
+    type Window<'a, const N: usize>
+    where
+        Self : 'a,
+    =
+        &'a mut [u8; N]
+    ;
+
+    fn chunk<const N: usize> (self: &'_ Bytes)
+      -> Option<Self::Chunk<N>>
+    {
+        self.0.get(.. N)?.try_into().ok()
+    }
+
+    fn window<'a, const N: usize> (self: &'a mut Bytes, start: usize)
+      -> Option<Self::Window<'a, N>>
+    {
+        self.0.get_mut(start ..)?.get_mut(.. N)?.try_into().ok()
+    }
+}
+
+#[apply(Gat!)]
+fn first_pair<B : Buffer> (buffer: &'_ B)
+  -> Option<<B as Buffer>::Chunk<2>>
+{
+    buffer.chunk::<2>()
+}
+
+#[test]
+fn const_gats ()
+{
+    let mut bytes = Bytes(vec![1, 2, 3]);
+    assert_eq!(first_pair(&bytes), Some([1, 2]));
+    let _: Option<Gat!(<Bytes as Buffer>::Chunk<4>)> = None;
+    *bytes.window::<2>(1).unwrap() = [0, 0];
+    assert_eq!(bytes.0, [1, 0, 0]);
+    assert!(bytes.window::<3>(1).is_none());
+}
+
+#[gat(dyn)]
+trait DynLendingIterator {
+    type Item<'next>
+    where
+        Self : 'next,
+    ;
+
+    fn next (
+        self: &'_ mut Self,
+    ) -> Option<Self::Item<'_>>
+    ;
+}
+
+#[gat(dyn)]
+impl<'lt, T, const WIDTH: usize>
+    DynLendingIterator
+for
+    WindowsMut<&'lt mut [T], WIDTH>
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next mut [T; WIDTH]
+    ;
+
+    fn next (self: &'_ mut WindowsMut<&'lt mut [T], WIDTH>)
+      -> Option<Self::Item<'_>>
+    {
+        LendingIterator::next(self)
+    }
+}
+
+#[apply(Gat!)]
+fn boxed_windows<'slice> (slice: &'slice mut [u8])
+  -> Box<dyn 'slice + for<'n> DynLendingIterator<Item<'n> = &'n mut [u8; 2]>>
+{
+    Box::new(WindowsMut::<_, 2> { slice, start: 0 })
+}
+
+#[apply(Gat!)]
+fn dyn_count<I : ?Sized + DynLendingIterator> (iter: &'_ mut I)
+  -> usize
+{
+    let mut count = 0;
+    while iter.next().is_some() {
+        count += 1;
+    }
+    count
+}
+
+#[apply(Gat!)]
+fn takes_dyn_compatible<I> (_: I)
+where
+    I : for<'n> DynLendingIterator<Item<'n> = &'n mut [u8; 2]>,
+{}
+
+type DynPairs<'slice> = Gat!(
+    dyn 'slice + for<'n> DynLendingIterator<Item<'n> = &'n mut [u8; 2]>
+);
+
+#[apply(Gat!)]
+struct BoxedPairs<'slice> {
+    iter: Box<dyn 'slice + for<'n> DynLendingIterator<Item<'n> = &'n mut [u8; 2]>>,
+}
+
+#[test]
+fn dyn_gats ()
+{
+    let mut array = [0_u8; 3];
+    let pairs: Box<DynPairs<'_>> = boxed_windows(&mut array);
+    let mut pairs = BoxedPairs { iter: pairs };
+    assert_eq!(dyn_count(&mut *pairs.iter), 2);
+
+    takes_dyn_compatible(WindowsMut::<_, 2> { slice: &mut [0_u8][..], start: 0 });
+    let mut array = [0, 1, 2, 3];
+    let mut iter = boxed_windows(&mut array);
+    while let Some(&mut [fst, ref mut snd]) = iter.next() {
+        *snd += fst;
+    }
+    drop(iter);
+    assert_eq!(dyn_count(&mut *boxed_windows(&mut array)), 3);
+    assert_eq!(array, [0, 1, 3, 6]);
+}
+
+#[gat]
+trait Borrowed {
+    type Target : ?Sized;
+
+    type Ref<'r>
+    where
+        Self : 'r,
+    =
+        &'r Self::Target
+    ;
+
+    fn borrowed (self: &'_ Self)
+      -> Self::Ref<'_>
+    ;
+}
+
+#[gat]
+impl Borrowed for String {
+    type Target = str;
+
+    fn borrowed (self: &'_ String)
+      -> &'_ str
+    {
+        self
+    }
+}
+
+#[gat]
+impl Borrowed for Bytes {
+    type Target = [u8];
+
+    type Ref<'r>
+    where
+        Self : 'r,
+    =
+        Option<&'r u8>
+    ;
+
+    fn borrowed (self: &'_ Bytes)
+      -> Option<&'_ u8>
+    {
+        self.0.first()
+    }
+}
+
+#[gat]
+trait Tagged<'tag, T> {
+    type With<'w>
+    where
+        Self : 'w,
+    =
+        (&'w Self, &'tag [T])
+    ;
+
+    fn with<'w> (self: &'w Self, tag: &'tag [T])
+      -> Self::With<'w>
+    ;
+}
+
+mod tagged_impl {
+    use nougat::{gat, Gat};
+
+    #[gat(With)]
+    use super::Tagged as Renamed;
+
+    #[gat]
+    impl<'tag> Renamed<'tag, char> for u8 {
+        fn with<'w> (self: &'w u8, tag: &'tag [char])
+          -> (&'w u8, &'tag [char])
+        {
+            (self, tag)
+        }
+    }
+}
+
+#[apply(Gat!)]
+fn borrowed<B : Borrowed> (it: &'_ B)
+  -> <B as Borrowed>::Ref<'_>
+{
+    it.borrowed()
+}
+
+#[test]
+fn defaulted_gats ()
+{
+    let s = String::from("hello");
+    let _: &str = borrowed(&s);
+    assert_eq!(borrowed(&s), "hello");
+    assert_eq!(borrowed(&Bytes(vec![42])), Some(&42));
+    let tag = ['a', 'b'];
+    let _: Gat!(<u8 as Tagged<'_, char>>::With<'_>) = 0_u8.with(&tag);
+}
+
+#[gat]
+trait Parser<'input> {
+    type Token<'t>
+    where
+        'input : 't,
+    ;
+
+    type Split<'t : 'rest, 'rest>;
+
+    fn token<'t> (input: &'t &'input str)
+      -> Self::Token<'t>
+    ;
+
+    fn split<'t, 'rest> (input: &'rest &'t str)
+      -> Self::Split<'t, 'rest>
+    ;
+}
+
+struct Words;
+
+#[gat]
+impl<'input> Parser<'input> for Words {
+    type Token<'t>
+    where
+        'input : 't,
+    =
+        &'t str
+    ;
+
+    type Split<'t : 'rest, 'rest> = (&'rest str, &'t str);
+
+    fn token<'t> (input: &'t &'input str)
+      -> &'t str
+    {
+        input.split(' ').next().unwrap_or("")
+    }
+
+    fn split<'t, 'rest> (input: &'rest &'t str)
+      -> (&'rest str, &'t str)
+    {
+        let input: &'t str = input;
+        let at = input.find(' ').unwrap_or(input.len());
+        (&input[.. at], &input[at ..])
+    }
+}
+
+#[apply(Gat!)]
+fn first_token<'t, 'input, P : Parser<'input>> (input: &'t &'input str)
+  -> <P as Parser<'input>>::Token<'t>
+{
+    P::token(input)
+}
+
+#[test]
+fn lifetime_outlives_bounds ()
+{
+    let input = "hello world";
+    assert_eq!(first_token::<Words>(&input), "hello");
+    assert_eq!(<Words as Parser<'_>>::split(&input), ("hello", " world"));
+}
+
+#[gat]
+trait Trimmer {
+    type Trimmed<'s, F>
+    where
+        for<'x> F : Fn(&'x str) -> &'x str,
+        Self : 's,
+    ;
+
+    fn trimmed<'s, F> (self: &'s Self, f: F)
+      -> Self::Trimmed<'s, F>
+    where
+        for<'x> F : Fn(&'x str) -> &'x str,
+    ;
+}
+
+#[gat]
+impl Trimmer for String {
+    type Trimmed<'s, F>
+    where
+        F : for<'x> Fn(&'x str) -> &'x str,
+        Self : 's,
+    =
+        (&'s str, F)
+    ;
+
+    fn trimmed<'s, F> (self: &'s String, f: F)
+      -> Self::Trimmed<'s, F>
+    where
+        for<'x> F : Fn(&'x str) -> &'x str,
+    {
+        (f(self), f)
+    }
+}
+
+// As with native GATs, the `impl` may omit the `where` clauses of the trait.
+#[gat]
+impl Trimmer for Box<str> {
+    type Trimmed<'s, F> = (&'s str, F);
+
+    fn trimmed<'s, F> (self: &'s Box<str>, f: F)
+      -> Self::Trimmed<'s, F>
+    where
+        for<'x> F : Fn(&'x str) -> &'x str,
+    {
+        (f(self), f)
+    }
+}
+
+#[test]
+fn higher_ranked_gat_predicates ()
+{
+    let s = String::from("  hi ");
+    assert_eq!(s.trimmed(str::trim).0, "hi");
+    let s: Box<str> = " hi".into();
+    assert_eq!(s.trimmed(str::trim_start).0, "hi");
+}
+
+#[gat]
+trait Views<T> {
+    type Copied<'a>
+    where
+        Self : 'a,
+        T : 'a + Copy,
+    ;
+
+    fn copied<'a> (self: &'a Self)
+      -> Self::Copied<'a>
+    ;
+}
+
+struct Wrapper<T>(Vec<T>);
+
+#[gat]
+impl<T : Copy> Views<T> for Wrapper<T> {
+    type Copied<'a>
+    where
+        Self : 'a,
+        T : 'a + Copy,
+    =
+        ::core::iter::Copied<::core::slice::Iter<'a, T>>
+    ;
+
+    fn copied<'a> (self: &'a Wrapper<T>)
+      -> ::core::iter::Copied<::core::slice::Iter<'a, T>>
+    {
+        self.0.iter().copied()
+    }
+}
+
+#[apply(Gat!)]
+fn copied<T, V : Views<T>> (views: &'_ V)
+  -> <V as Views<T>>::Copied<'_>
+{
+    views.copied()
+}
+
+#[gat]
+trait Sorting {
+    type Sorted<T>
+    where
+        T : Ord,
+    ;
+
+    fn sorted<T> (items: Vec<T>)
+      -> Self::Sorted<T>
+    where
+        T : Ord,
+    ;
+}
+
+#[gat]
+impl Sorting for Vec_ {
+    type Sorted<T>
+    where
+        T : Ord,
+    =
+        Vec<T>
+    ;
+
+    fn sorted<T> (mut items: Vec<T>)
+      -> Self::Sorted<T>
+    where
+        T : Ord,
+    {
+        items.sort();
+        items
+    }
+}
+
+struct Heap_;
+
+// The `where` clauses of the `impl` may be written differently.
+#[gat]
+impl Sorting for Heap_ {
+    type Sorted<T>
+    where
+        T : ::core::cmp::Ord,
+    =
+        Vec<T>
+    ;
+
+    fn sorted<T> (items: Vec<T>)
+      -> Self::Sorted<T>
+    where
+        T : ::core::cmp::Ord,
+    {
+        ::std::collections::BinaryHeap::from(items).into_sorted_vec()
+    }
+}
+
+#[apply(Gat!)]
+fn sorted<S : Sorting, T : Ord> (items: Vec<T>)
+  -> <S as Sorting>::Sorted<T>
+{
+    S::sorted(items)
+}
+
+#[test]
+fn trait_bound_gat_predicates ()
+{
+    let w = Wrapper(vec![1, 2, 3]);
+    assert_eq!(copied(&w).sum::<i32>(), 6);
+    assert_eq!(sorted::<Vec_, _>(vec![3, 1, 2]), [1, 2, 3]);
+    assert_eq!(sorted::<Heap_, _>(vec![3, 1, 2]), [1, 2, 3]);
+}
+
+#[gat]
+trait Windows {
+    type Window<'w> : Iterator
+    where
+        Self : 'w,
+    ;
+
+    fn windowed<'w> (self: &'w Self)
+      -> Self::Window<'w>
+    ;
+
+    fn first<'w> (self: &'w Self)
+      -> Option<Self::Window<'w>::Item>
+    {
+        self.windowed().next()
+    }
+}
+
+#[gat]
+impl Windows for Bytes {
+    type Window<'w>
+    where
+        Self : 'w,
+    =
+        ::core::slice::Iter<'w, u8>
+    ;
+
+    fn windowed<'w> (self: &'w Bytes)
+      -> ::core::slice::Iter<'w, u8>
+    {
+        self.0.iter()
+    }
+}
+
+#[apply(Gat!)]
+fn last<'w, W : Windows> (windows: &'w W)
+  -> Option<<W as Windows>::Window<'w>::Item>
+{
+    windows.windowed().last()
+}
+
+#[gat]
+trait Nest {
+    type Inner<'a> : LendingIterator
+    where
+        Self : 'a,
+    ;
+}
+
+#[gat]
+impl Nest for Bytes {
+    type Inner<'a>
+    where
+        Self : 'a,
+    =
+        Infinite
+    ;
+}
+
+#[test]
+fn nested_gat_paths ()
+{
+    let bytes = Bytes(vec![1, 2, 3]);
+    assert_eq!(bytes.first(), Some(&1));
+    assert_eq!(last(&bytes), Some(&3));
+    let mut infinite = Infinite;
+    let _: Gat!(
+        <<Bytes as Nest>::Inner<'_> as LendingIterator>::Item<'_>
+    ) = LendingIterator::next(&mut infinite).unwrap();
+    // Several GAT hops.
+    let _: Gat!(
+        <Bytes as Nest>::Inner<'_>::Item<'_>
+    ) = LendingIterator::next(&mut infinite).unwrap();
+}
+
+#[apply(Gat!)]
+fn next_item<I : LendingIterator + Send> (iter: &'_ mut I)
+  -> Option<I::Item<'_>>
+{
+    iter.next()
+}
+
+#[apply(Gat!)]
+fn first_window<'w, W> (windows: &'w W)
+  -> Option<W::Window<'w>::Item>
+where
+    W : Windows,
+{
+    windows.first()
+}
+
+#[apply(Gat!)]
+fn sorted_shorthand<S : Sorting, T : Ord> (items: Vec<T>)
+  -> S::Sorted<T>
+{
+    S::sorted(items)
+}
+
+#[apply(Gat!)]
+struct Peeked<'lt, I : 'lt + LendingIterator> {
+    iter: &'lt mut I,
+    peeked: Option<I::Item<'lt>>,
+}
+
+#[test]
+fn bound_shorthand ()
+{
+    let mut infinite = Infinite;
+    let _: Option<&mut Infinite> = next_item(&mut infinite);
+    assert_eq!(first_window(&Bytes(vec![4, 2])), Some(&4));
+    assert_eq!(sorted_shorthand::<Vec_, _>(vec![2, 1]), [1, 2]);
+    let mut infinite = Infinite;
+    let peeked = Peeked { iter: &mut infinite, peeked: None };
+    assert!(peeked.peeked.is_none());
+}
+
+#[gat(Self : LendingIterator + MultiLendingIterator)]
+impl Infinite {
+    fn lend (self: &'_ mut Self)
+      -> Option<Self::Item<'_>>
+    {
+        LendingIterator::next(self)
+    }
+
+    fn lend_first (self: &'_ mut Self)
+      -> Option<Self::Item1<'_>>
+    {
+        MultiLendingIterator::next(self).map(|(first, _)| first)
+    }
+}
+
+#[gat(Self : LendingIterator)]
+trait LendingIteratorExt : LendingIterator {
+    fn lend_next (self: &'_ mut Self)
+      -> Option<Self::Item<'_>>
+    {
+        self.next()
+    }
+}
+
+impl<I : LendingIterator> LendingIteratorExt for I {}
+
+#[test]
+fn self_bounds ()
+{
+    let mut infinite = Infinite;
+    let _: &mut Infinite = infinite.lend().unwrap();
+    let _: &Infinite = infinite.lend_first().unwrap();
+    let _: &mut Infinite = infinite.lend_next().unwrap();
+}
+
+struct Infinite4;
+
+mod infinite4_impl {
+    use super::Infinite4;
+    use nougat::{gat, Gat};
+
+    // No need to list the GATs.
+    #[gat]
+    use super::LendingIterator as LendingIteratorRenamed;
+    #[gat]
+    use super::MultiLendingIterator;
+    #[gat]
+    use super::{DynLendingIterator as DynLendingIteratorRenamed};
+
+    #[gat]
+    impl LendingIteratorRenamed for Infinite4 {
+        type Item<'next>
+        where
+            Self : 'next,
+        =
+            &'next mut Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<&'_ mut Self>
+        {
+            Some(self)
+        }
+    }
+
+    #[gat]
+    impl MultiLendingIterator for Infinite4 {
+        type Item1<'next>
+        where
+            Self : 'next,
+        =
+            &'next Self
+        ;
+
+        type Item2<'next>
+        where
+            Self : 'next,
+        =
+            &'next Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<(&'_ Self, &'_ Self)>
+        {
+            Some((self, self))
+        }
+    }
+
+    #[gat]
+    impl DynLendingIteratorRenamed for Infinite4 {
+        type Item<'next>
+        where
+            Self : 'next,
+        =
+            &'next mut Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<&'_ mut Self>
+        {
+            Some(self)
+        }
+    }
+}
+
+#[test]
+fn unlisted_gat_uses ()
+{
+    let mut infinite = Infinite4;
+    let _: &mut Infinite4 = LendingIterator::next(&mut infinite).unwrap();
+    let _: (&Infinite4, &Infinite4) =
+        MultiLendingIterator::next(&mut infinite).unwrap()
+    ;
+    let _: &mut Infinite4 = DynLendingIterator::next(&mut infinite).unwrap();
+}
+
+mod prelude {
+    #[::nougat::gat]
+    pub(crate) use crate::{
+        LendingIterator as Lending,
+        MultiLendingIterator,
+    };
+
+    pub(crate) mod glob {
+        #[::nougat::gat]
+        pub(crate) use crate::*;
+    }
+}
+
+struct Infinite5;
+
+mod infinite5_impl {
+    use super::Infinite5;
+    use nougat::{gat, Gat};
+
+    #[gat]
+    use super::prelude::{self, glob::{DynLendingIterator as Dyn}};
+
+    #[gat]
+    impl crate::prelude::Lending for Infinite5 {
+        type Item<'next>
+        where
+            Self : 'next,
+        =
+            &'next mut Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<&'_ mut Self>
+        {
+            Some(self)
+        }
+    }
+
+    #[gat]
+    impl prelude::MultiLendingIterator for Infinite5 {
+        type Item1<'next>
+        where
+            Self : 'next,
+        =
+            &'next Self
+        ;
+
+        type Item2<'next>
+        where
+            Self : 'next,
+        =
+            &'next Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<(&'_ Self, &'_ Self)>
+        {
+            Some((self, self))
+        }
+    }
+
+    #[gat]
+    impl Dyn for Infinite5 {
+        type Item<'next>
+        where
+            Self : 'next,
+        =
+            &'next mut Self
+        ;
+
+        fn next (
+            self: &'_ mut Self,
+        ) -> Option<&'_ mut Self>
+        {
+            Some(self)
+        }
+    }
+}
+
+#[test]
+fn glob_and_group_gat_uses ()
+{
+    let mut infinite = Infinite5;
+    let _: &mut Infinite5 = LendingIterator::next(&mut infinite).unwrap();
+    let _: (&Infinite5, &Infinite5) =
+        MultiLendingIterator::next(&mut infinite).unwrap()
+    ;
+    let _: &mut Infinite5 = DynLendingIterator::next(&mut infinite).unwrap();
+}
+
+/// An owning lending iterator: yields `self.0 - 1`, …, `0`.
+#[derive(Clone)]
+struct Countdown(u8);
+
+#[gat]
+impl LendingIterator for Countdown {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next mut u8
+    ;
+
+    fn next (self: &'_ mut Countdown)
+      -> Option<&'_ mut u8>
+    {
+        self.0 = self.0.checked_sub(1)?;
+        Some(&mut self.0)
+    }
+}
+
+#[apply(Gat!)]
+fn debug_all<I> (mut iter: I)
+  -> Vec<String>
+where
+    I : for<'n> LendingIterator<Item<'n> : ::core::fmt::Debug + Send>,
+{
+    let mut ret = vec![];
+    while let Some(item) = iter.next() {
+        ret.push(format!("{:?}", item));
+    }
+    ret
+}
+
+#[gat]
+trait Inspector {
+    type Seen<'s>
+    where
+        Self : 's,
+    ;
+
+    fn first_debugged<I : for<'n> LendingIterator<Item<'n> : ::core::fmt::Debug>> (
+        self: &'_ Self,
+        iter: I,
+    ) -> Option<String>
+    ;
+}
+
+#[gat]
+impl Inspector for () {
+    type Seen<'s>
+    where
+        Self : 's,
+    =
+        &'s ()
+    ;
+
+    fn first_debugged<I : for<'n> LendingIterator<Item<'n> : ::core::fmt::Debug>> (
+        self: &'_ (),
+        mut iter: I,
+    ) -> Option<String>
+    {
+        iter.next().map(|it| format!("{:?}", it))
+    }
+}
+
+/// Generic callers can forward these bounds, even on the MSRV.
+#[apply(Gat!)]
+fn debug_all_twice<I> (iter: I)
+  -> Vec<String>
+where
+    I : Clone + for<'n> LendingIterator<Item<'n> : ::core::fmt::Debug + Send>,
+{
+    let mut ret = debug_all(iter.clone());
+    ret.extend(debug_all(iter));
+    ret
+}
+
+/// For a given (named) lifetime, the bounds are not higher-ranked, and can
+/// thus be checked against a concrete type on the MSRV as well.
+#[apply(Gat!)]
+fn debug_next<'iter, I> (iter: &'iter mut I)
+  -> Option<String>
+where
+    I : LendingIterator<Item<'iter> : ::core::fmt::Debug>,
+{
+    iter.next().map(|it| format!("{:?}", it))
+}
+
+#[apply(Gat!)]
+fn debug_next_send<'iter, I> (iter: &'iter mut I)
+  -> Option<String>
+where
+    I : LendingIterator<Item<'iter> : ::core::fmt::Debug + Send>,
+{
+    debug_next(iter)
+}
+
+#[test]
+fn gat_bounds_sugar_for_a_given_lifetime ()
+{
+    let mut countdown = Countdown(3);
+    assert_eq!(debug_next(&mut countdown), Some("2".into()));
+    assert_eq!(debug_next_send(&mut countdown), Some("1".into()));
+    assert_eq!(debug_next(&mut countdown), Some("0".into()));
+    assert_eq!(debug_next(&mut countdown), None);
+}
+
+/// The bounds sugar on a concrete type.
+#[cfg(feature = "docs")]
+#[apply(Gat!)]
+fn debug_countdown (iter: Countdown)
+  -> Vec<String>
+where
+    Countdown : for<'n> LendingIterator<Item<'n> : ::core::fmt::Debug + Send>,
+{
+    debug_all(iter)
+}
+
+/// Older compilers (such as the MSRV) fail to check these higher-ranked bounds
+/// against a concrete type (see the `Gat!` docs).
+#[cfg(feature = "docs")]
+#[test]
+fn gat_bounds_sugar ()
+{
+    assert_eq!(debug_all(Countdown(3)), ["2", "1", "0"]);
+    assert_eq!(debug_all_twice(Countdown(2)), ["1", "0", "1", "0"]);
+    assert_eq!(debug_countdown(Countdown(2)), ["1", "0"]);
+    assert_eq!(().first_debugged(Countdown(3)), Some("2".into()));
+}
+
+#[gat]
+trait ChunkParser<'src, T, const N: usize> {
+    type Error;
+
+    type Parsed<'p>;
+
+    fn parse<'p> (self: &'p mut Self, src: &'src [T])
+      -> Result<Self::Parsed<'p>, Self::Error>
+    ;
+}
+
+/// Parses `N`-wide chunks, keeping count of them.
+struct Chunker {
+    count: usize,
+}
+
+#[gat]
+impl<'src, T : Copy, const N: usize> ChunkParser<'src, T, N> for Chunker {
+    type Error = ();
+
+    type Parsed<'p> = (&'p mut usize, [T; N]);
+
+    fn parse<'p> (self: &'p mut Chunker, src: &'src [T])
+      -> Result<(&'p mut usize, [T; N]), ()>
+    {
+        let chunk = src.get(.. N).ok_or(())?.try_into().map_err(drop)?;
+        self.count += 1;
+        Ok((&mut self.count, chunk))
+    }
+}
+
+#[apply(Gat!)]
+fn parse_twice<'src, P> (parser: &'_ mut P, src: &'src [u8])
+  -> Option<[u8; 2]>
+where
+    P : for<'p> ChunkParser<'src, u8, 2, Error = (), Parsed<'p> = (&'p mut usize, [u8; 2])>,
+{
+    parser.parse(src).ok()?;
+    let (_, chunk) = parser.parse(src).ok()?;
+    Some(chunk)
+}
+
+#[gat]
+trait Labeled<Tag = ()> {
+    type Label<'s>
+    where
+        Self : 's,
+    ;
+
+    fn label (self: &'_ Self)
+      -> Self::Label<'_>
+    ;
+}
+
+struct Named(String);
+
+#[gat]
+impl Labeled for Named {
+    type Label<'s>
+    where
+        Self : 's,
+    =
+        &'s str
+    ;
+
+    fn label (self: &'_ Named)
+      -> &'_ str
+    {
+        &self.0
+    }
+}
+
+#[gat]
+impl Labeled<usize> for Named {
+    type Label<'s>
+    where
+        Self : 's,
+    =
+        (&'s str, usize)
+    ;
+
+    fn label (self: &'_ Named)
+      -> (&'_ str, usize)
+    {
+        (&self.0, self.0.len())
+    }
+}
+
+#[apply(Gat!)]
+fn labels<L> (it: &'_ L)
+  -> (<L as Labeled>::Label<'_>, <L as Labeled<usize>>::Label<'_>)
+where
+    L : for<'s> Labeled<Label<'s> = &'s str>,
+    L : for<'s> Labeled<usize, Label<'s> = (&'s str, usize)>,
+{
+    (Labeled::<()>::label(it), Labeled::<usize>::label(it))
+}
+
+#[test]
+fn mixed_trait_generics ()
+{
+    let mut chunker = Chunker { count: 0 };
+    assert_eq!(parse_twice(&mut chunker, &[1, 2, 3]), Some([1, 2]));
+    let (count, chunk): Gat!(<Chunker as ChunkParser<'_, u8, 3>>::Parsed<'_>) =
+        chunker.parse(&[4, 5, 6]).unwrap()
+    ;
+    assert_eq!((*count, chunk), (3, [4, 5, 6]));
+
+    let named = Named("nougat".into());
+    assert_eq!(labels(&named), ("nougat", ("nougat", 6)));
+}
+
+#[apply(Gat!)]
+fn returns_elided_impl_ty<T> (slice: &'_ mut [T])
+  -> impl '_ + LendingIterator<Item<'_> = &'_ mut [T; 2]>
+{
+    WindowsMut::<_, 2> { slice, start: 0 }
+}
+
+#[apply(Gat!)]
+fn takes_elided_impl_ty<T> (
+    mut iter: impl LendingIterator<Item<'_> = &'_ mut [T; 2]>,
+) -> usize
+where
+    T : Copy + ::core::ops::AddAssign,
+{
+    let mut count = 0;
+    while let Some([fst, snd]) = iter.next() {
+        *snd += *fst;
+        count += 1;
+    }
+    count
+}
+
+#[apply(Gat!)]
+fn takes_elided_dyn (
+    iter: &'_ mut (dyn '_ + DynLendingIterator<Item<'_> = &'_ mut [u8; 2]>),
+) -> Option<[u8; 2]>
+{
+    iter.next().copied()
+}
+
+#[apply(Gat!)]
+fn elided_in_where_clause<I> (mut iter: I)
+  -> Option<[u8; 2]>
+where
+    I : LendingIterator<Item<'_> = &'_ mut [u8; 2]>,
+{
+    iter.next().copied()
+}
+
+#[test]
+fn elided_gat_lifetimes ()
+{
+    let mut array = [0_u8, 1, 2, 3];
+    assert_eq!(takes_elided_impl_ty(returns_elided_impl_ty(&mut array)), 3);
+    assert_eq!(array, [0, 1, 3, 6]);
+    assert_eq!(
+        elided_in_where_clause(returns_elided_impl_ty(&mut array)),
+        Some([0, 1]),
+    );
+    let mut iter = WindowsMut::<_, 2> { slice: &mut array[1 ..], start: 0 };
+    assert_eq!(takes_elided_dyn(&mut iter), Some([1, 3]));
+}
+
+#[gat]
+trait Container {
+    type Elem : ?Sized;
+
+    type Lend<'a> : for<'b> LendingIterator<Item<'b> = &'b Self::Elem>
+    where
+        Self : 'a,
+    ;
+
+    type IntoLend : LendingIterator<Item<'_> = &'_ Self::Elem>;
+
+    fn lend<'a> (self: &'a Self)
+      -> Self::Lend<'a>
+    ;
+
+    fn into_lend (self: Self)
+      -> Self::IntoLend
+    ;
+}
+
+struct Strings(Vec<String>);
+
+struct Lent<'a>(&'a [String]);
+
+#[gat]
+impl<'a> LendingIterator for Lent<'a> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next str
+    ;
+
+    fn next (self: &'_ mut Lent<'a>)
+      -> Option<&'_ str>
+    {
+        let (first, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(first)
+    }
+}
+
+struct IntoLent(::std::vec::IntoIter<String>, Option<String>);
+
+#[gat]
+impl LendingIterator for IntoLent {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next str
+    ;
+
+    fn next (self: &'_ mut IntoLent)
+      -> Option<&'_ str>
+    {
+        self.1 = Some(self.0.next()?);
+        self.1.as_deref()
+    }
+}
+
+#[gat]
+impl Container for Strings {
+    type Elem = str;
+
+    type Lend<'a>
+    where
+        Self : 'a,
+    =
+        Lent<'a>
+    ;
+
+    type IntoLend = IntoLent;
+
+    fn lend<'a> (self: &'a Strings)
+      -> Lent<'a>
+    {
+        Lent(&self.0)
+    }
+
+    fn into_lend (self: Strings)
+      -> IntoLent
+    {
+        IntoLent(self.0.into_iter(), None)
+    }
+}
+
+#[apply(Gat!)]
+fn concat<C : Container<Elem = str>> (container: C)
+  -> String
+{
+    let mut ret = String::new();
+    {
+        let mut lend = container.lend();
+        while let Some(s) = lend.next() {
+            ret += s;
+        }
+    }
+    let mut lend = container.into_lend();
+    while let Some(s) = lend.next() {
+        ret += &s.to_uppercase();
+    }
+    ret
+}
+
+#[test]
+fn gat_bindings_in_assoc_type_bounds ()
+{
+    let strings = Strings(vec!["a".into(), "b".into()]);
+    assert_eq!(concat(strings), "abAB");
+}
+
+#[gat]
+trait Cell {
+    type Ref<'a> : Copy
+    where
+        Self : 'a,
+    ;
+
+    type Mut<'a> : Into<Self::Ref<'a>>
+    where
+        Self : 'a,
+    ;
+
+    type Both<'a>
+    where
+        Self : 'a,
+    ;
+
+    fn get_mut<'a> (self: &'a mut Self)
+      -> Self::Mut<'a>
+    ;
+}
+
+struct Shared<'a, T>(&'a T);
+
+impl<T> Clone for Shared<'_, T> {
+    fn clone (self: &'_ Self)
+      -> Self
+    {
+        *self
+    }
+}
+
+impl<T> Copy for Shared<'_, T> {}
+
+struct Exclusive<'a, T>(&'a mut T);
+
+impl<'a, T> From<Exclusive<'a, T>> for Shared<'a, T> {
+    fn from (it: Exclusive<'a, T>)
+      -> Shared<'a, T>
+    {
+        Shared(it.0)
+    }
+}
+
+struct Slot<T>(T);
+
+#[gat]
+impl<T> Cell for Slot<T> {
+    type Ref<'a>
+    where
+        Self : 'a,
+    =
+        Shared<'a, T>
+    ;
+
+    type Mut<'a>
+    where
+        Self : 'a,
+    =
+        Exclusive<'a, T>
+    ;
+
+    type Both<'a>
+    where
+        Self : 'a,
+    =
+        (Self::Ref<'a>, Self::Mut<'a>)
+    ;
+
+    fn get_mut<'a> (self: &'a mut Slot<T>)
+      -> Exclusive<'a, T>
+    {
+        Exclusive(&mut self.0)
+    }
+}
+
+#[apply(Gat!)]
+fn downgrade<C : Cell> (cell: &'_ mut C)
+  -> C::Ref<'_>
+{
+    cell.get_mut().into()
+}
+
+#[test]
+fn sibling_gats ()
+{
+    let mut slot = Slot(42);
+    let shared = downgrade(&mut slot);
+    assert_eq!(*shared.0, 42);
+    let mut other = 27;
+    let _: Gat!(<Slot<i32> as Cell>::Both<'_>) =
+        (shared, Exclusive(&mut other))
+    ;
+}
+
+#[gat]
+trait DoubleEndedLendingIterator : LendingIterator + Send {
+    fn next_back (self: &'_ mut Self)
+      -> Option<Self::Item<'_>>
+    ;
+
+    fn second_to_last (self: &'_ mut Self)
+      -> Option<Self::Item<'_>>
+    {
+        let _ = self.next_back()?;
+        self.next_back()
+    }
+}
+
+/// Lends the element last taken off either end.
+struct Deque(Vec<u8>, u8);
+
+#[gat]
+impl LendingIterator for Deque {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next mut u8
+    ;
+
+    fn next (self: &'_ mut Deque)
+      -> Option<&'_ mut u8>
+    {
+        if self.0.is_empty() {
+            return None;
+        }
+        self.1 = self.0.remove(0);
+        Some(&mut self.1)
+    }
+}
+
+#[gat]
+impl DoubleEndedLendingIterator for Deque {
+    fn next_back (self: &'_ mut Deque)
+      -> Option<Self::Item<'_>>
+    {
+        self.1 = self.0.pop()?;
+        Some(&mut self.1)
+    }
+}
+
+#[apply(Gat!)]
+fn back<I : DoubleEndedLendingIterator> (iter: &'_ mut I)
+  -> Option<I::Item<'_>>
+{
+    iter.next_back()
+}
+
+#[test]
+fn inherited_gats ()
+{
+    let mut deque = Deque(vec![1, 2, 3, 4], 0);
+    assert_eq!(deque.next().map(|it| *it), Some(1));
+    assert_eq!(back(&mut deque).map(|it| *it), Some(4));
+    assert_eq!(deque.second_to_last().map(|it| *it), Some(2));
+    assert!(deque.next_back().is_none());
+}
+
+/// The supertrait is given other generic args than the trait's own.
+#[gat]
+trait Peek<T> : LendingIterator {
+    fn peek (self: &'_ mut Self, hint: T)
+      -> Option<Self::Item<'_>>
+    ;
+}
+
+#[gat]
+impl Peek<()> for Deque {
+    fn peek (self: &'_ mut Deque, (): ())
+      -> Option<Self::Item<'_>>
+    {
+        self.1 = *self.0.first()?;
+        Some(&mut self.1)
+    }
+}
+
+#[apply(Gat!)]
+fn peek<I : Peek<()>> (iter: &'_ mut I)
+  -> Option<<I as LendingIterator>::Item<'_>>
+{
+    iter.peek(())
+}
+
+#[test]
+fn inherited_gats_with_other_args ()
+{
+    let mut deque = Deque(vec![1, 2], 0);
+    assert_eq!(peek(&mut deque).map(|it| *it), Some(1));
+    assert_eq!(deque.next().map(|it| *it), Some(1));
+    assert_eq!(deque.peek(()).map(|it| *it), Some(2));
+}
+
+#[gat]
+trait Source {
+    #[gat(covariant)]
+    type Chunk<'data>
+    where
+        Self : 'data,
+    ;
+
+    fn slice<'data> (self: &'data Self)
+      -> Self::Chunk<'data>
+    ;
+}
+
+struct Text(String);
+
+#[gat]
+impl Source for Text {
+    type Chunk<'data>
+    where
+        Self : 'data,
+    =
+        &'data str
+    ;
+
+    fn slice<'data> (self: &'data Text)
+      -> &'data str
+    {
+        &self.0
+    }
+}
+
+#[apply(Gat!)]
+fn shortest_lived<'short, 'long : 'short, S : Source> (
+    long: S::Chunk<'long>,
+    short: S::Chunk<'short>,
+    pick_long: bool,
+) -> S::Chunk<'short>
+{
+    if pick_long {
+        S::upcast_chunk(long)
+    } else {
+        short
+    }
+}
+
+#[test]
+fn covariant_gats ()
+{
+    let long = Text("long".into());
+    let short = Text("short".into());
+    let chunk = shortest_lived::<Text>(long.slice(), short.slice(), true);
+    assert_eq!(chunk, "long");
+}
+
+/// (The added bounds are warned about.)
+#[allow(deprecated)]
+mod auto_outlives {
+    use super::*;
+
+    #[gat(auto_outlives)]
+    trait Streaming {
+        type Item<'next>;
+
+        fn next (self: &'_ mut Self)
+          -> Option<Self::Item<'_>>
+        ;
+    }
+
+    #[gat(auto_outlives)]
+    trait Lookup<K> {
+        type Entry<'map>;
+
+        fn get<'map> (self: &'map Self, key: &'map K)
+          -> Option<Self::Entry<'map>>
+        ;
+    }
+
+    #[gat]
+    impl<K : PartialEq> Lookup<K> for Vec<(K, String)> {
+        type Entry<'map> = (&'map K, &'map str);
+
+        fn get<'map> (self: &'map Vec<(K, String)>, key: &'map K)
+          -> Option<(&'map K, &'map str)>
+        {
+            self.iter().find(|(k, _)| k == key).map(|(k, v)| (k, &**v))
+        }
+    }
+
+    #[gat(auto_outlives)]
+    trait Borrowing {
+        type Ref<'r>;
+
+        fn borrow (self: &'_ Self)
+          -> Self::Ref<'_>
+        ;
+    }
+
+    #[gat]
+    impl<T> Borrowing for Vec<T> {
+        type Ref<'r> = &'r [T];
+
+        fn borrow (self: &'_ Vec<T>)
+          -> &'_ [T]
+        {
+            self
+        }
+    }
+
+    /// The output lifetime is elided from the only reference among the inputs.
+    #[gat(auto_outlives)]
+    trait Project<T> {
+        type Field<'f>;
+
+        fn project (it: &'_ T)
+          -> Self::Field<'_>
+        ;
+    }
+
+    #[gat]
+    impl<T> Project<T> for () {
+        type Field<'f> = &'f T;
+
+        fn project (it: &'_ T)
+          -> &'_ T
+        {
+            it
+        }
+    }
+
+    #[gat]
+    impl Streaming for Countdown {
+        type Item<'next> = &'next mut u8;
+
+        fn next (self: &'_ mut Countdown)
+          -> Option<&'_ mut u8>
+        {
+            self.0 = self.0.checked_sub(1)?;
+            Some(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn auto_outlives ()
+    {
+        let mut countdown = Countdown(2);
+        assert_eq!(Streaming::next(&mut countdown).map(|it| *it), Some(1));
+        let map = vec![(1, "one".to_owned())];
+        assert_eq!(map.get(&1), Some((&1, "one")));
+        assert_eq!(vec![1, 2].borrow(), [1, 2]);
+        assert_eq!(*<() as Project<i32>>::project(&3), 3);
+    }
+}
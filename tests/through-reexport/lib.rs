@@ -0,0 +1,60 @@
+#![allow(unused)]
+
+pub use ::renamed_nougat as reexported_nougat;
+
+use reexported_nougat::*;
+
+#[gat(crate = crate::reexported_nougat)]
+pub trait LendingIterator {
+    type Item<'next>
+    where
+        Self : 'next,
+    ;
+
+    fn next (&mut self)
+      -> Option<Self::Item<'_>>
+    ;
+}
+
+pub struct WindowsMut<'slice> {
+    slice: &'slice mut [u8],
+    start: usize,
+}
+
+#[gat]
+impl<'slice> LendingIterator for WindowsMut<'slice> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        &'next mut [u8]
+    ;
+
+    fn next (&mut self)
+      -> Option<&'_ mut [u8]>
+    {
+        let to_yield = self.slice.get_mut(self.start ..)?.get_mut(.. 2)?;
+        self.start += 1;
+        Some(to_yield)
+    }
+}
+
+#[apply(Gat!)]
+fn sum_firsts<I : LendingIterator> (mut iter: I)
+  -> u32
+where
+    for<'n> I : LendingIterator<Item<'n> = &'n mut [u8]>,
+{
+    let mut sum = 0;
+    while let Some(window) = iter.next() {
+        sum += u32::from(window[0]);
+    }
+    sum
+}
+
+#[test]
+fn through_reexport ()
+{
+    let ref mut slice = [1, 2, 3, 4];
+    assert_eq!(sum_firsts(WindowsMut { slice, start: 0 }), 1 + 2 + 3);
+}
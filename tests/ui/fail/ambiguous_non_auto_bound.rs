@@ -0,0 +1,29 @@
+//! Only the auto traits (such as `Send`) are ruled out of the shorthand: any
+//! other bound may provide the GAT, so the path has to be qualified.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+#[apply(Gat!)]
+fn next_send<I : LendingIterator + Send> (it: &mut I) -> Option<I::Item<'_>> {
+    it.next()
+}
+
+#[apply(Gat!)]
+fn next_clone<I : LendingIterator + Clone> (_: &mut I) -> Option<I::Item<'_>> {
+    None
+}
+
+#[apply(Gat!)]
+fn next_qualified<I : LendingIterator + Clone> (it: &mut I)
+  -> Option<<I as LendingIterator>::Item<'_>>
+{
+    it.next()
+}
+
+fn main ()
+{}
@@ -0,0 +1,25 @@
+//! The ambiguous path is stubbed, so that the rest of the signature (and its
+//! callers) yield no further errors.
+use ::nougat::*;
+
+#[gat]
+trait Foo { type Item<'item> where Self : 'item; }
+#[gat]
+trait Bar { type Item<'item> where Self : 'item; }
+
+#[apply(Gat!)]
+fn f<T : Foo + Bar> (_: &T, _: Option<T::Item<'_>>)
+{}
+
+#[gat(Self : Foo + Bar)]
+trait Baz : Foo + Bar {
+    fn g (&self) -> Option<Self::Item<'_>> { None }
+}
+
+fn main ()
+{
+    struct Both;
+    #[gat] impl Foo for Both { type Item<'item> = (); }
+    #[gat] impl Bar for Both { type Item<'item> = (); }
+    f(&Both, None);
+}
@@ -0,0 +1,19 @@
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+trait Len {
+    fn len (&self) -> usize;
+}
+
+#[gat]
+trait ExactSizeLendingIterator : LendingIterator + Len {
+    fn next_exact (&mut self) -> Option<(usize, Self::Item<'_>)>;
+}
+
+fn main ()
+{}
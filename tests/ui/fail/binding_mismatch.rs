@@ -0,0 +1,27 @@
+//! Out of reach of `#[diagnostic::on_unimplemented]`: still reported in terms
+//! of the helper trait.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+struct Countdown(u8);
+
+#[gat]
+impl LendingIterator for Countdown {
+    type Item<'next> = u8;
+    fn next (&mut self) -> Option<u8> { None }
+}
+
+#[apply(Gat!)]
+fn first_str<I : for<'n> LendingIterator<Item<'n> = &'n str>> (mut it: I) {
+    let _ = it.next();
+}
+
+fn main ()
+{
+    first_str(Countdown(3));
+}
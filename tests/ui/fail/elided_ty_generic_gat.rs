@@ -0,0 +1,10 @@
+use ::nougat::*;
+
+#[gat]
+trait Container {
+    type Elem<'a, T> where Self : 'a;
+    fn first<T> (&self) -> Option<Self::Elem<'_, T>>;
+}
+
+fn main ()
+{}
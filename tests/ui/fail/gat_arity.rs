@@ -0,0 +1,18 @@
+use ::nougat::*;
+
+#[gat]
+trait Container {
+    type Elem<'a, T> where Self : 'a;
+    fn first<'a, T> (&'a self) -> Option<Self::Elem<'a, T>>;
+}
+
+#[gat]
+impl Container for () {
+    type Elem<'a> = &'a ();
+    fn first<'a, T> (&'a self) -> Option<Self::Elem<'a, T>> { None }
+}
+
+fn main ()
+{
+    let _ = Container::first::<u8>(&());
+}
@@ -0,0 +1,21 @@
+//! The impl is still emitted (with the GAT stubbed), so that the uses of the
+//! trait yield no further errors.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+struct Empty;
+
+#[gat]
+impl LendingIterator for Empty {
+    fn next (&mut self) -> Option<()> { None }
+}
+
+fn main ()
+{
+    let _ = LendingIterator::next(&mut Empty);
+}
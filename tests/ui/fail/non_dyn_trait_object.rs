@@ -0,0 +1,14 @@
+//! The `#[gat]` trait is told apart from the auto traits of the trait object,
+//! wherever it is listed.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+type Erased = Gat!(dyn Send + LendingIterator);
+
+fn main ()
+{}
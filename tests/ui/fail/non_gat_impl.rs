@@ -0,0 +1,18 @@
+//! With the `diagnostics` feature, the missing helper `impl` is reported in
+//! terms of the GAT.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+struct Countdown(u8);
+
+impl LendingIterator for Countdown {
+    fn next (&mut self) -> Option<u8> { None }
+}
+
+fn main ()
+{}
@@ -0,0 +1,21 @@
+use ::nougat::*;
+
+#[gat]
+trait Container {
+    type Elem<'a, T> where Self : 'a, T : ::core::cmp::Ord;
+}
+
+#[gat]
+impl Container for () {
+    type Elem<'a, T>
+    where
+        (): 'a,
+        T : Ord + Clone,
+        Vec<T> : Clone,
+    =
+        Option<T>
+    ;
+}
+
+fn main ()
+{}
@@ -0,0 +1,19 @@
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+#[gat]
+impl LendingIterator for () {
+    type Item<'next> = ();
+    type Itme<'next> = ();
+    fn next (&mut self) -> Option<()> { None }
+}
+
+fn main ()
+{
+    let _ = LendingIterator::next(&mut ());
+}
@@ -0,0 +1,31 @@
+//! Only the `for<'x>` predicate errors: the trait, its `impl`, and its users
+//! still compile against the stubbed `Item<'next>` (see
+//! `pass/unsupported_gat.rs` for the same code without that predicate).
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next, for<'x> &'x Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+struct Countdown(u8);
+
+#[gat]
+impl LendingIterator for Countdown {
+    type Item<'next> = u8;
+    fn next (&mut self) -> Option<u8> {
+        self.0 = self.0.checked_sub(1)?;
+        Some(self.0)
+    }
+}
+
+#[apply(Gat!)]
+fn first<I : LendingIterator> (it: &mut I) -> Option<I::Item<'_>> {
+    it.next()
+}
+
+fn main ()
+{
+    assert_eq!(first(&mut Countdown(3)), Some(2));
+}
@@ -0,0 +1,7 @@
+#[test]
+fn ui ()
+{
+    let t = ::trybuild::TestCases::new();
+    t.compile_fail("fail/*.rs");
+    t.pass("pass/*.rs");
+}
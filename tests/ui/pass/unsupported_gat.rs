@@ -0,0 +1,29 @@
+//! `fail/unsupported_gat.rs`, minus its unsupported predicate.
+use ::nougat::*;
+
+#[gat]
+trait LendingIterator {
+    type Item<'next> where Self : 'next;
+    fn next (&mut self) -> Option<Self::Item<'_>>;
+}
+
+struct Countdown(u8);
+
+#[gat]
+impl LendingIterator for Countdown {
+    type Item<'next> = u8;
+    fn next (&mut self) -> Option<u8> {
+        self.0 = self.0.checked_sub(1)?;
+        Some(self.0)
+    }
+}
+
+#[apply(Gat!)]
+fn first<I : LendingIterator> (it: &mut I) -> Option<I::Item<'_>> {
+    it.next()
+}
+
+fn main ()
+{
+    assert_eq!(first(&mut Countdown(3)), Some(2));
+}